@@ -1,5 +1,6 @@
+use encoding_rs::SHIFT_JIS;
 use std::ffi::CStr;
-use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom};
+use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
 use std::mem::transmute;
 use utf16string::{WStr, WString};
 
@@ -17,11 +18,15 @@ impl ByteOrderExt for LE {
 
 pub trait ReadExt {
     fn read_slice<const N: usize>(&mut self) -> Result<[u8; N]>;
-    fn read_bits<B: ByteOrder>(&mut self) -> Result<u8>;
     fn read_cstring(&mut self) -> Result<String>;
+    fn read_shift_jis_cstring(&mut self) -> Result<String>;
     fn read_wide_cstring<B: ByteOrder + 'static>(&mut self) -> Result<String>;
 }
 
+pub trait WriteExt {
+    fn write_zeros(&mut self, count: usize) -> Result<()>;
+}
+
 pub trait ReadSliceExt<'a> {
     fn read_slice_ref(&mut self, size: usize) -> Result<&'a [u8]>;
     fn read_fixed_slice_ref<const N: usize>(&mut self) -> Result<&'a [u8; N]>;
@@ -48,17 +53,17 @@ impl<'a, T: AsRef<[u8]> + 'a> ReadSliceExt<'a> for Cursor<T> {
     fn read_fixed_slice_ref<const N: usize>(&mut self) -> Result<&'a [u8; N]> {
         let slice = self.read_slice_ref(N)?;
         // Safety: `slice` is a N-byte slice
-        unsafe { Ok(std::mem::transmute(slice.as_ptr())) }
+        unsafe { Ok(&*(slice.as_ptr() as *const [u8; N])) }
     }
 
     fn read_fixed_str(&mut self, size: usize) -> Result<&'a str> {
-        Ok(CStr::from_bytes_until_nul(self.read_slice_ref(size)?)
+        CStr::from_bytes_until_nul(self.read_slice_ref(size)?)
             .or(Err(Error::new(
                 ErrorKind::InvalidData,
                 "Non-terminated C string",
             )))?
             .to_str()
-            .or(Err(Error::new(ErrorKind::InvalidData, "Non-UTF8 C string")))?)
+            .or(Err(Error::new(ErrorKind::InvalidData, "Non-UTF8 C string")))
     }
 
     fn read_fixed_wstr<B: ByteOrder>(&mut self, size: usize) -> Result<&'a WStr<B>> {
@@ -70,7 +75,7 @@ impl<'a, T: AsRef<[u8]> + 'a> ReadSliceExt<'a> for Cursor<T> {
                 ErrorKind::InvalidData,
                 "Non-terminated wide C string",
             ))?;
-        WStr::from_utf16(bytes.get(..null_pos).unwrap()).or(Err(Error::new(
+        WStr::from_utf16(bytes.get(..2 * null_pos).unwrap()).or(Err(Error::new(
             ErrorKind::InvalidData,
             "Non-UTF16 wide C read",
         )))
@@ -112,7 +117,7 @@ where
         let cpos = self.stream_position()?;
         let end = self.seek(SeekFrom::End(0))?;
         self.seek(SeekFrom::Start(cpos))?;
-        Ok(cpos - end)
+        Ok(end - cpos)
     }
 
     fn do_at<T>(&mut self, pos: SeekFrom, action: impl Fn(&mut Self) -> Result<T>) -> Result<T> {
@@ -134,34 +139,25 @@ where
         Ok(buf)
     }
 
-    fn read_bits<B: ByteOrder>(&mut self) -> Result<u8> {
-        // Cursed, please ignore
-        let mut byte = self.read_u8()?;
-        #[cfg(target_endian = "little")]
-        let val = 0x0001;
-        #[cfg(target_endian = "big")]
-        let val = 0x0100;
-        if B::read_i16(&[1u8, 0u8]) != val {
-            byte.reverse_bits();
-        }
-        Ok(byte)
-    }
-
     fn read_cstring(&mut self) -> Result<String> {
-        let mut buf = Vec::new();
-        loop {
-            let b = self.read_u8()?;
-            if b == 0 {
-                break;
-            }
-            buf.push(b);
-        }
-        String::from_utf8(buf).or(Err(Error::new(
+        String::from_utf8(read_cstring_bytes(self)?).or(Err(Error::new(
             ErrorKind::InvalidData,
             "Invalid UTF8 string",
         )))
     }
 
+    fn read_shift_jis_cstring(&mut self) -> Result<String> {
+        let buf = read_cstring_bytes(self)?;
+        let (s, had_errors) = SHIFT_JIS.decode_without_bom_handling(&buf);
+        if had_errors {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Invalid Shift-JIS string",
+            ));
+        }
+        Ok(s.into_owned())
+    }
+
     fn read_wide_cstring<B: ByteOrder + 'static>(&mut self) -> Result<String> {
         let mut buf = Vec::new();
         loop {
@@ -180,6 +176,49 @@ where
     }
 }
 
+fn read_cstring_bytes(r: &mut impl Read) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    loop {
+        let b = r.read_u8()?;
+        if b == 0 {
+            break;
+        }
+        buf.push(b);
+    }
+    Ok(buf)
+}
+
+impl<W> WriteExt for W
+where
+    W: Write,
+{
+    fn write_zeros(&mut self, count: usize) -> Result<()> {
+        std::io::copy(&mut std::io::repeat(0).take(count as u64), self)?;
+        Ok(())
+    }
+}
+
+/// Encodes `s` as a null-terminated UTF-16 string.
+pub fn wide_cstring_bytes<B: ByteOrder + 'static>(s: &str) -> Vec<u8> {
+    let mut bytes = WString::<B>::from(s).into_bytes();
+    bytes.extend([0, 0]);
+    bytes
+}
+
+/// Encodes `s` as a null-terminated Shift-JIS string.
+pub fn shift_jis_cstring_bytes(s: &str) -> Result<Vec<u8>> {
+    let (bytes, _, had_errors) = SHIFT_JIS.encode(s);
+    if had_errors {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("String {:?} cannot be encoded in Shift-JIS", s),
+        ));
+    }
+    let mut bytes = bytes.into_owned();
+    bytes.push(0);
+    Ok(bytes)
+}
+
 pub fn assert_read(pred: bool, msg: impl AsRef<str>) -> Result<()> {
     pred.then_some(())
         .ok_or(Error::new(ErrorKind::InvalidData, msg.as_ref()))
@@ -190,21 +229,20 @@ pub struct StackReader<'a, R: Read + Seek> {
     stack: Vec<u64>,
 }
 impl<'a, R: Read + Seek> StackReader<'a, R> {
-    fn new(reader: &'a mut R) -> Self {
+    pub fn new(reader: &'a mut R) -> Self {
         Self {
             reader,
             stack: Vec::new(),
         }
     }
 
-    fn seek_push(&mut self, pos: SeekFrom) -> Result<u64> {
+    pub fn seek_push(&mut self, pos: SeekFrom) -> Result<u64> {
         self.stack.push(self.reader.stream_position()?);
         self.reader.seek(pos)
     }
 
-    fn seek_pop(&mut self) -> Result<u64> {
-        let pos = self.stack.pop().ok_or(Error::new(
-            ErrorKind::Other,
+    pub fn seek_pop(&mut self) -> Result<u64> {
+        let pos = self.stack.pop().ok_or(Error::other(
             "Cannot pop() a StackReader with no saved positions",
         ))?;
         self.reader.seek(SeekFrom::Start(pos))
@@ -213,11 +251,11 @@ impl<'a, R: Read + Seek> StackReader<'a, R> {
 impl<'a, R: Read + Seek> std::ops::Deref for StackReader<'a, R> {
     type Target = R;
     fn deref(&self) -> &Self::Target {
-        &self.reader
+        self.reader
     }
 }
 impl<'a, R: Read + Seek> std::ops::DerefMut for StackReader<'a, R> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.reader
+        self.reader
     }
 }
@@ -1,12 +1,15 @@
 use byteorder::*;
-use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, SeekFrom::*};
+use std::io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom::*, Write};
 
 use crate::binary_utils::*;
-use crate::dcx::DCX;
+use crate::dcx::{Kind, DCX};
 
 #[derive(Default)]
 pub struct Header {
+    pub unk04: bool,
+    pub unk05: bool,
     pub big_endian: bool,
+    pub bit_big_endian: bool,
     pub file_count: u32,
     pub header_size: u64,
     pub version: [u8; 8],
@@ -21,27 +24,21 @@ pub struct Header {
 impl Header {
     pub fn read(reader: &mut impl Read) -> Result<Header> {
         assert_read(&reader.read_slice()? == b"BND4", "Not a BND4")?;
-        assert_read(
-            {
-                let b = reader.read_u8()?;
-                b == 0 || b == 1
-            },
-            "Unexpected value for unk04",
-        )?;
-        assert_read(
-            {
-                let b = reader.read_u8()?;
-                b == 0 || b == 1
-            },
-            "Unexpected value for unk05",
-        )?;
+        let unk04 = reader.read_u8()?;
+        assert_read(unk04 == 0 || unk04 == 1, "Unexpected value for unk04")?;
+        let unk05 = reader.read_u8()?;
+        assert_read(unk05 == 0 || unk05 == 1, "Unexpected value for unk05")?;
 
         assert_read(reader.read_u8()? == 0, "Unexpected value for unk06")?;
         assert_read(reader.read_u8()? == 0, "Unexpected value for unk07")?;
         assert_read(reader.read_u8()? == 0, "Unexpected value for unk08")?;
 
-        let mut header = Header::default();
-        header.big_endian = reader.read_u8()? != 0;
+        let mut header = Header {
+            unk04: unk04 != 0,
+            unk05: unk05 != 0,
+            big_endian: reader.read_u8()? != 0,
+            ..Default::default()
+        };
 
         if header.big_endian {
             header.read_after_endian::<BE>(reader)?;
@@ -52,13 +49,12 @@ impl Header {
     }
 
     fn read_after_endian<B: ByteOrder>(&mut self, reader: &mut impl Read) -> Result<()> {
+        let bit_little_endian = reader.read_u8()?;
         assert_read(
-            {
-                let b = reader.read_u8()?;
-                b == 0 || b == 1
-            },
+            bit_little_endian == 0 || bit_little_endian == 1,
             "Unexpected value for unk0A",
         )?;
+        self.bit_big_endian = bit_little_endian == 0;
         assert_read(reader.read_u8()? == 0, "Unexpected value for unk0B")?;
         self.file_count = reader.read_u32::<B>()?;
         self.header_size = reader.read_u64::<B>()?;
@@ -67,7 +63,7 @@ impl Header {
         self.file_headers_size = reader.read_u64::<B>()?;
         self.file_headers_end = reader.read_u64::<B>()?;
         self.unicode = reader.read_u8()? != 0;
-        self.format = reader.read_bits::<B>()?;
+        self.format = Self::format_from_raw(reader.read_u8()?, self.bit_big_endian);
         self.extended = reader.read_u8()?;
         assert_read(
             self.extended == 0 || self.extended == 4,
@@ -80,7 +76,75 @@ impl Header {
         Ok(())
     }
 
+    fn write_endian<B: ByteOrder>(
+        &self,
+        w: &mut impl Write,
+        file_count: u32,
+        file_headers_end: u64,
+        bucket_offset: u64,
+    ) -> Result<()> {
+        w.write_all(b"BND4")?;
+        w.write_u8(self.unk04 as u8)?;
+        w.write_u8(self.unk05 as u8)?;
+        w.write_zeros(3)?;
+        w.write_u8(self.big_endian as u8)?;
+        w.write_u8(!self.bit_big_endian as u8)?;
+        w.write_u8(0)?;
+        w.write_u32::<B>(file_count)?;
+        w.write_u64::<B>(0x40)?;
+        w.write_all(&self.version)?;
+        w.write_u64::<B>(Self::file_header_size(self.format))?;
+        w.write_u64::<B>(file_headers_end)?;
+        w.write_u8(self.unicode as u8)?;
+        w.write_u8(Self::format_to_raw(self.format, self.bit_big_endian))?;
+        w.write_u8(self.extended)?;
+        w.write_zeros(5)?;
+        w.write_u64::<B>(bucket_offset)
+    }
+
+    /// The format byte is stored bit-reversed, unless the archive is bit big endian or the format
+    /// has the big endian bit (and not the most significant one) set.
+    fn format_from_raw(raw: u8, bit_big_endian: bool) -> u8 {
+        let as_is = bit_big_endian || ((raw & 1) != 0 && (raw & 0x80) == 0);
+        match as_is {
+            true => raw,
+            false => raw.reverse_bits(),
+        }
+    }
+
+    fn format_to_raw(format: u8, bit_big_endian: bool) -> u8 {
+        let as_is = bit_big_endian || ((format & 1) != 0 && (format & 0x80) == 0);
+        match as_is {
+            true => format,
+            false => format.reverse_bits(),
+        }
+    }
+
+    /// Size of a single file header for the given format.
+    pub fn file_header_size(format: u8) -> u64 {
+        let mut size = 0x10;
+        if (format & Self::FORMAT_COMPRESSED) != 0 {
+            size += 8;
+        }
+        size += if (format & Self::FORMAT_LONG_OFFSETS) != 0 {
+            8
+        } else {
+            4
+        };
+        if (format & Self::FORMAT_HASH) != 0 {
+            size += 4;
+        }
+        if (format & Self::FORMAT_NAMES) != 0 {
+            size += 4;
+        }
+        if format == Self::FORMAT_NAME_SPECIAL {
+            size += 8;
+        }
+        size
+    }
+
     pub const FORMAT_COMPRESSED: u8 = 0b00100000;
+    pub const FORMAT_LONG_OFFSETS: u8 = 0b00010000;
     pub const FORMAT_HASH: u8 = 0b00000010;
     pub const FORMAT_HAS_ID: u8 = 0b00000110;
     pub const FORMAT_NAMES: u8 = 0b00001100;
@@ -96,6 +160,20 @@ pub struct File {
     pub data: Vec<u8>,
 }
 
+impl File {
+    /// The file's data is individually DCX compressed.
+    pub const FLAG_COMPRESSED: u8 = 0b00000001;
+
+    /// File flags are stored bit-reversed unless the archive is bit big endian. This is its own
+    /// inverse.
+    fn flags_from_raw(raw: u8, bit_big_endian: bool) -> u8 {
+        match bit_big_endian {
+            true => raw,
+            false => raw.reverse_bits(),
+        }
+    }
+}
+
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
 pub struct Bucket {
     pub count: u32,
@@ -113,6 +191,8 @@ pub struct BND4 {
     pub files: Vec<File>,
     pub buckets: Vec<Bucket>,
     pub hashes: Vec<Hash>,
+    /// DCX variant the archive was compressed with, if any.
+    pub dcx: Option<Kind>,
 }
 
 impl BND4 {
@@ -127,8 +207,10 @@ impl BND4 {
             Err(e) => {
                 if DCX::is(&reader.at(start)?.read_slice::<4>()?) {
                     reader.seek(Start(start))?;
-                    let out = DCX::decompress(reader)?;
-                    return Self::read(&mut Cursor::new(out.as_slice()));
+                    let (out, kind) = DCX::decompress_with_kind(reader)?;
+                    let mut bnd = Self::read(&mut Cursor::new(out.as_slice()))?;
+                    bnd.dcx = Some(kind);
+                    return Ok(bnd);
                 } else {
                     Err(e)
                 }
@@ -147,11 +229,10 @@ impl BND4 {
         start: u64,
         r: &mut (impl Read + Seek),
     ) -> Result<BND4> {
-        let file_headers_end = start + header.file_headers_end;
         let mut files = Vec::with_capacity(header.file_count as usize);
 
         for _ in 0..header.file_count {
-            let flags = r.read_bits::<B>()?;
+            let flags = File::flags_from_raw(r.read_u8()?, header.bit_big_endian);
 
             assert_read(
                 &r.read_slice()? == b"\0\0\0",
@@ -160,26 +241,34 @@ impl BND4 {
             assert_read(r.read_i32::<B>()? == -1, "Unexpected file header non -1")?;
 
             let disk_size = r.read_u64::<B>()?;
-            let uncompressed_size =
-                ((header.format & Header::FORMAT_COMPRESSED) != 0).then_some(r.read_u64::<B>()?);
-            let data_offset = r.read_u32::<B>()? as u64;
-
-            let mut id = ((header.format & Header::FORMAT_HASH) != 0).then_some(r.read_u32::<B>()?);
-
-            let name = ((header.format & Header::FORMAT_NAMES) != 0).then_some({
+            let uncompressed_size = if (header.format & Header::FORMAT_COMPRESSED) != 0 {
+                Some(r.read_u64::<B>()?)
+            } else {
+                None
+            };
+            let data_offset = if (header.format & Header::FORMAT_LONG_OFFSETS) != 0 {
+                r.read_u64::<B>()?
+            } else {
+                r.read_u32::<B>()? as u64
+            };
+
+            let mut id = if (header.format & Header::FORMAT_HASH) != 0 {
+                Some(r.read_u32::<B>()?)
+            } else {
+                None
+            };
+
+            let name = if (header.format & Header::FORMAT_NAMES) != 0 {
                 let name_offset = r.read_u32::<B>()? as u64;
-                let cpos = r.stream_position()?;
-                r.seek(Start(start + name_offset))?;
-
-                let name = if header.unicode {
-                    r.read_wide_cstring::<B>()?
-                } else {
-                    r.read_cstring()?
-                };
-
-                r.seek(Start(cpos))?;
-                name
-            });
+                Some(
+                    r.do_at(Start(start + name_offset), |r| match header.unicode {
+                        true => r.read_wide_cstring::<B>(),
+                        false => r.read_shift_jis_cstring(),
+                    })?,
+                )
+            } else {
+                None
+            };
 
             if header.format == Header::FORMAT_NAME_SPECIAL {
                 id = Some(r.read_u32::<B>()?);
@@ -239,6 +328,219 @@ impl BND4 {
             files,
             buckets,
             hashes,
+            dcx: None,
         })
     }
+
+    /// FromSoftware path hash of a file name, as used in the BND4 hash table.
+    pub fn path_hash(path: &str) -> u32 {
+        let mut hashable = path.trim().replace('\\', "/").to_lowercase();
+        if !hashable.starts_with('/') {
+            hashable.insert(0, '/');
+        }
+        hashable
+            .encode_utf16()
+            .fold(0u32, |h, c| h.wrapping_mul(37).wrapping_add(c as u32))
+    }
+
+    /// Computes the hash table of the given files. Files are grouped in a prime number of buckets
+    /// by the hash of their name, and sorted by hash within each bucket.
+    pub fn hash_table(files: &[File]) -> (Vec<Bucket>, Vec<Hash>) {
+        let bucket_count = (files.len() as u32 / 7..)
+            .find(|&n| n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0))
+            .unwrap();
+
+        let mut groups = vec![Vec::new(); bucket_count as usize];
+        for (i, file) in files.iter().enumerate() {
+            let hash = Self::path_hash(file.name.as_deref().unwrap_or_default());
+            groups[(hash % bucket_count) as usize].push(Hash {
+                hash,
+                index: i as u32,
+            });
+        }
+
+        let mut buckets = Vec::with_capacity(groups.len());
+        let mut hashes = Vec::with_capacity(files.len());
+        for mut group in groups {
+            group.sort_by_key(|h| h.hash);
+            buckets.push(Bucket {
+                count: group.len() as u32,
+                index: hashes.len() as u32,
+            });
+            hashes.extend(group);
+        }
+        (buckets, hashes)
+    }
+
+    /// Serializes the BND4. The header offsets and the hash table are recomputed from the files,
+    /// so an unmodified archive is written back identically.
+    pub fn write(&self, w: &mut impl Write) -> Result<()> {
+        if self.header.big_endian {
+            self.write_endian::<BE>(w)
+        } else {
+            self.write_endian::<LE>(w)
+        }
+    }
+
+    /// Serializes the BND4, compressing it in the DCX variant it was read in if any.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)?;
+        match self.dcx {
+            Some(kind) => DCX::compress(&bytes, kind),
+            None => Ok(bytes),
+        }
+    }
+
+    fn write_endian<B: ByteOrder + 'static>(&self, w: &mut impl Write) -> Result<()> {
+        let header = &self.header;
+        let has_names = (header.format & Header::FORMAT_NAMES) != 0;
+        let missing =
+            |what: &str| Error::new(ErrorKind::InvalidInput, format!("File has no {}", what));
+
+        // Compute the layout of the archive
+        let names = self
+            .files
+            .iter()
+            .filter(|_| has_names)
+            .map(|f| {
+                let name = f.name.as_deref().ok_or_else(|| missing("name"))?;
+                match header.unicode {
+                    true => Ok(wide_cstring_bytes::<B>(name)),
+                    false => shift_jis_cstring_bytes(name),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut pos = 0x40 + self.files.len() as u64 * Header::file_header_size(header.format);
+        let mut name_offsets = Vec::with_capacity(names.len());
+        for name in &names {
+            name_offsets.push(pos);
+            pos += name.len() as u64;
+        }
+        let names_end = pos;
+
+        let (buckets, hashes) = match header.extended {
+            4 => Self::hash_table(&self.files),
+            _ => (Vec::new(), Vec::new()),
+        };
+        let bucket_offset = match header.extended {
+            4 => {
+                pos = (pos + 7) & !7;
+                let ofs = pos;
+                pos += 0x10 + 8 * (buckets.len() + hashes.len()) as u64;
+                ofs
+            }
+            _ => 0,
+        };
+        let file_headers_end = pos;
+
+        let mut data_offsets = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            if !file.data.is_empty() {
+                pos = (pos + 0xF) & !0xF;
+            }
+            data_offsets.push(pos);
+            pos += file.data.len() as u64;
+        }
+
+        // Write everything
+        header.write_endian::<B>(w, self.files.len() as u32, file_headers_end, bucket_offset)?;
+
+        for (i, file) in self.files.iter().enumerate() {
+            w.write_u8(File::flags_from_raw(file.flags, header.bit_big_endian))?;
+            w.write_zeros(3)?;
+            w.write_i32::<B>(-1)?;
+            w.write_u64::<B>(file.data.len() as u64)?;
+            if (header.format & Header::FORMAT_COMPRESSED) != 0 {
+                w.write_u64::<B>(match (file.flags & File::FLAG_COMPRESSED) != 0 {
+                    true => file
+                        .uncompressed_size
+                        .ok_or_else(|| missing("uncompressed size"))?,
+                    false => file.data.len() as u64,
+                })?;
+            }
+            if (header.format & Header::FORMAT_LONG_OFFSETS) != 0 {
+                w.write_u64::<B>(data_offsets[i])?;
+            } else {
+                w.write_u32::<B>(data_offsets[i] as u32)?;
+            }
+            if (header.format & Header::FORMAT_HASH) != 0 {
+                w.write_u32::<B>(file.id.ok_or_else(|| missing("ID"))?)?;
+            }
+            if has_names {
+                w.write_u32::<B>(name_offsets[i] as u32)?;
+            }
+            if header.format == Header::FORMAT_NAME_SPECIAL {
+                w.write_u32::<B>(file.id.ok_or_else(|| missing("ID"))?)?;
+                w.write_u32::<B>(0)?;
+            }
+        }
+
+        for name in &names {
+            w.write_all(name)?;
+        }
+
+        if header.extended == 4 {
+            w.write_zeros((bucket_offset - names_end) as usize)?;
+            w.write_u64::<B>(bucket_offset + 0x10 + 8 * buckets.len() as u64)?;
+            w.write_u32::<B>(buckets.len() as u32)?;
+            w.write_all(&[0x10, 8, 8, 0])?;
+            for bucket in &buckets {
+                w.write_u32::<B>(bucket.count)?;
+                w.write_u32::<B>(bucket.index)?;
+            }
+            for hash in &hashes {
+                w.write_u32::<B>(hash.hash)?;
+                w.write_u32::<B>(hash.index)?;
+            }
+        }
+
+        pos = file_headers_end;
+        for (file, &offset) in self.files.iter().zip(&data_offsets) {
+            w.write_zeros((offset - pos) as usize)?;
+            w.write_all(&file.data)?;
+            pos = offset + file.data.len() as u64;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Game, ER};
+
+    #[test]
+    fn write_unmodified_is_identical() {
+        let encrypted = std::fs::read("regulations/er").unwrap();
+        let dcx = ER::decrypt_regulation_data(&encrypted).unwrap().data;
+        let original = DCX::decompress(&mut Cursor::new(dcx.as_slice())).unwrap();
+
+        let bnd = BND4::read(&mut Cursor::new(original.as_slice())).unwrap();
+        assert_eq!(
+            BND4::hash_table(&bnd.files),
+            (bnd.buckets.clone(), bnd.hashes.clone())
+        );
+
+        let mut written = Vec::new();
+        bnd.write(&mut written).unwrap();
+        assert!(
+            written == original,
+            "written BND4 differs from the original"
+        );
+    }
+
+    #[test]
+    fn read_zstd_dcx() {
+        let encrypted = std::fs::read("regulations/er").unwrap();
+        let mut bnd = ER::decrypt_regulation(&encrypted).unwrap();
+        bnd.files.truncate(3);
+        bnd.dcx = Some(Kind::DCX_ZSTD(0x15));
+
+        let compressed = bnd.to_bytes().unwrap();
+        let read = BND4::read(&mut Cursor::new(compressed.as_slice())).unwrap();
+        assert_eq!(read.dcx, bnd.dcx);
+        assert!(read.files == bnd.files);
+    }
 }
@@ -1,33 +1,99 @@
 use crate::{
     bnd4::BND4,
     param::*,
-    paramdex_reader::{self, ParamdexDB},
-    xml_meta::{ParamMeta, ParamMetaEnum},
-    xml_paramdef::{DefBaseRustType, DefBaseType, DefField, DefType, DefTypeModifier, Paramdef},
+    paramdex_reader::ParamdexDB,
+    xml_meta::{ParamEnumOption, ParamMeta, ParamMetaEnum, ParamMetaField},
+    xml_paramdef::{DefBaseRustType, DefBaseType, DefField, DefTypeModifier, Paramdef},
 };
 use std::{
-    collections::HashMap,
-    fmt::{self, Error, Result, Write},
-    io,
-    path::PathBuf,
-    str::FromStr,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::{self, Result, Write},
+    fs, io,
+    path::Path,
 };
 
 pub struct CodegenParams {
+    /// Generate doc comments, including the field and enum descriptions of the paramdef and Meta.
     pub docs: bool,
     pub field_enums: bool,
+    /// Generate enums as transparent newtypes with an associated constant per value, so that
+    /// fields accept values missing from the Meta. Otherwise, row data is checked when cast.
+    pub newtype_enums: bool,
     pub name_enums: bool,
+    pub ref_ids: bool,
     pub reflection: bool,
     pub private_dummy8: bool,
+    /// Generate structs for big endian param files, even if the paramdef is not marked as big
+    /// endian. Their multi-byte fields use the wrapper types of [`crate::endian`], so enums are
+    /// only used as the type of single byte fields and bitfields.
+    pub big_endian: bool,
+    /// Generate serde implementations for the param structs, enums and ID newtypes. Bitfields are
+    /// serialized like other fields and `dummy8` padding is skipped, keeping its value when
+    /// deserializing into an existing row with [`crate::row_serde::deserialize_into`]. Enums are
+    /// serialized by name, and can be deserialized from their name or value.
+    pub serde: bool,
+    /// Generate the param struct of every DefsPatch version of a paramdef in a `v{version}`
+    /// module (`v0` being the base paramdef), with conversions between consecutive versions. The
+    /// struct of the target version is re-exported in place of the single struct otherwise
+    /// generated.
+    pub versions: bool,
 }
 impl Default for CodegenParams {
     fn default() -> Self {
         CodegenParams {
             docs: true,
             field_enums: true,
+            newtype_enums: false,
             name_enums: true,
+            ref_ids: true,
             reflection: false,
             private_dummy8: true,
+            big_endian: false,
+            serde: false,
+            versions: false,
+        }
+    }
+}
+
+/// A field whose Meta or paramdef names an enum which cannot be used as its type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnumDiagnostic {
+    pub param: String,
+    pub field: String,
+    pub enum_name: String,
+    pub kind: EnumDiagnosticKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumDiagnosticKind {
+    /// The enum is defined neither in the Meta file of the param nor in the Tdfs.
+    Missing,
+    /// The enum has a different integer type than the field.
+    TypeMismatch {
+        enum_type: DefBaseType,
+        field_type: DefBaseType,
+    },
+    /// The paramdef default of the field, or 0 if it has none, is not one of the values of an enum
+    /// which is not generated as a newtype.
+    InvalidDefault { default: i64 },
+}
+
+impl fmt::Display for EnumDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result {
+        write!(f, "{}.{}: enum {} ", self.param, self.field, self.enum_name)?;
+        match self.kind {
+            EnumDiagnosticKind::Missing => write!(f, "is not defined"),
+            EnumDiagnosticKind::TypeMismatch {
+                enum_type,
+                field_type,
+            } => write!(
+                f,
+                "has type {:?} but the field has type {:?}",
+                enum_type, field_type
+            ),
+            EnumDiagnosticKind::InvalidDefault { default } => {
+                write!(f, "has no value {} for the default of the field", default)
+            }
         }
     }
 }
@@ -44,9 +110,7 @@ impl<'a> RustCodegen<'a> {
         for file in &regulation.files {
             if let Some(path) = &file.name {
                 if let Some(no_ext) = path.strip_suffix(".param") {
-                    let base_name = no_ext
-                        .get(path.rfind(|c| c == '\\' || c == '/').unwrap() + 1..)
-                        .unwrap();
+                    let base_name = no_ext.get(path.rfind(['\\', '/']).unwrap() + 1..).unwrap();
 
                     let param_file = ParamFile::new(&file.data)?;
                     game_params.insert(base_name, param_file);
@@ -61,55 +125,401 @@ impl<'a> RustCodegen<'a> {
         })
     }
 
-    fn gen_enum(&self, e: &ParamMetaEnum, out: &mut impl Write) -> fmt::Result {
-        if e.base_type.rust_type() == DefBaseRustType::F32 {
-            return Err(fmt::Error);
+    /// Returns the parsed param file of the given name from the regulation passed on construction.
+    pub fn game_param(&self, name: &str) -> Option<&ParamFile<'a>> {
+        self.game_params.get(name)
+    }
+
+    fn gen_enum(
+        &self,
+        e: &ParamMetaEnum,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        if is_newtype_enum(e, config) {
+            self.gen_enum_newtype(e, config, out)?;
+        } else {
+            self.gen_enum_rust(e, config, out)?;
         }
+        if config.serde {
+            self.gen_enum_serde(e, config, out)?;
+        }
+        Ok(())
+    }
 
+    fn gen_enum_rust(
+        &self,
+        e: &ParamMetaEnum,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        let variants = variant_idents(&e.options);
         writeln!(
             out,
-            "#[repr({})]\npub enum {} {{",
+            "#[repr({})]\n#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\npub enum {} {{",
             e.base_type.rust_type(),
             &e.name
         )?;
+        for (opt, variant) in e.options.iter().zip(&variants) {
+            self.gen_doc_comment(&opt.name, config, out)?;
+            writeln!(out, "{} = {},", variant, opt.value)?;
+        }
+        writeln!(out, "}}")?;
+
+        writeln!(
+            out,
+            "impl ::std::fmt::Display for {} {{
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+f.write_str(match self {{",
+            &e.name
+        )?;
+        for (opt, variant) in e.options.iter().zip(&variants) {
+            writeln!(out, "Self::{} => {:?},", variant, opt.name)?;
+        }
+        writeln!(out, "}})\n}}\n}}")?;
+
+        let int_type = e.base_type.rust_type();
+        writeln!(
+            out,
+            "impl TryFrom<{int_type}> for {} {{
+type Error = {int_type};
+fn try_from(value: {int_type}) -> ::std::result::Result<Self, {int_type}> {{
+match value {{",
+            &e.name
+        )?;
+        for (opt, variant) in e.options.iter().zip(&variants) {
+            writeln!(out, "{} => Ok(Self::{}),", opt.value, variant)?;
+        }
+        writeln!(out, "_ => Err(value),\n}}\n}}\n}}")
+    }
+
+    /// Generates serde implementations of an enum, using the names of its options when they
+    /// identify a single value.
+    fn gen_enum_serde(
+        &self,
+        e: &ParamMetaEnum,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        let int_type = e.base_type.rust_type();
+        writeln!(
+            out,
+            "impl {} {{\nconst SERDE_OPTIONS: &'static [(&'static str, {})] = &[",
+            &e.name, int_type
+        )?;
         for opt in &e.options {
-            let fixed_name : String = opt.name.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
-            writeln!(out, "{} = {},", fixed_name, opt.value)?;
+            writeln!(out, "({:?}, {}),", opt.name, option_literal(opt, int_type))?;
         }
-        writeln!(out, "}}")
+        writeln!(out, "];\n}}")?;
+
+        let (raw_value, from_raw) = match is_newtype_enum(e, config) {
+            true => ("self.0".to_owned(), "Ok(Self(value))".to_owned()),
+            false => (
+                format!("*self as {}", int_type),
+                format!(
+                    "Self::try_from(value).map_err(|v| {{
+<D::Error as ::paramdef_codegen::serde::de::Error>::custom(format!(\"{{}} is not a value of {}\", v))
+}})",
+                    &e.name
+                ),
+            ),
+        };
+        writeln!(
+            out,
+            r#"impl ::paramdef_codegen::serde::Serialize for {name} {{
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {{
+::paramdef_codegen::row_serde::serialize_enum(Self::SERDE_OPTIONS, {raw_value}, serializer)
+}}
+}}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for {name} {{
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {{
+let value = ::paramdef_codegen::row_serde::deserialize_enum(deserializer, "{name}", Self::SERDE_OPTIONS)?;
+{from_raw}
+}}
+}}"#,
+            name = &e.name
+        )
+    }
+
+    /// Generates an enum as a transparent newtype over its integer type with an associated
+    /// constant per option, for enums which cannot be a Rust enum or do not list every value.
+    fn gen_enum_newtype(
+        &self,
+        e: &ParamMetaEnum,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        let int_type = e.base_type.rust_type();
+        let derives = match int_type {
+            DefBaseRustType::F32 => "Clone, Copy, Debug, Default, PartialEq, PartialOrd",
+            _ => "Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash",
+        };
+        writeln!(
+            out,
+            "#[repr(transparent)]\n#[derive({})]\npub struct {}(pub {});",
+            derives, &e.name, int_type
+        )?;
+
+        let variants = variant_idents(&e.options);
+        writeln!(out, "#[allow(non_upper_case_globals)]\nimpl {} {{", &e.name)?;
+        for (opt, variant) in e.options.iter().zip(&variants) {
+            self.gen_doc_comment(&opt.name, config, out)?;
+            writeln!(
+                out,
+                "pub const {}: Self = Self({});",
+                variant,
+                option_literal(opt, int_type)
+            )?;
+        }
+        writeln!(out, "}}")?;
+
+        writeln!(
+            out,
+            "impl ::std::fmt::Display for {} {{
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+let names: &[(Self, &str)] = &[",
+            &e.name
+        )?;
+        for (opt, variant) in e.options.iter().zip(&variants) {
+            writeln!(out, "(Self::{}, {:?}),", variant, opt.name)?;
+        }
+        writeln!(
+            out,
+            "];
+match names.iter().find(|(value, _)| value == self) {{
+Some((_, name)) => f.write_str(name),
+None => ::std::fmt::Display::fmt(&self.0, f),
+}}
+}}
+}}"
+        )
     }
 
-    fn gen_doc_comment(&self, doc: impl AsRef<str>, out: &mut impl Write) -> Result {
+    fn gen_doc_comment(
+        &self,
+        doc: impl AsRef<str>,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        if !config.docs {
+            return Ok(());
+        }
         for line in doc.as_ref().split('\n') {
             writeln!(out, "/// {}", line)?;
         }
         Ok(())
     }
 
-    fn gen_field_doc(&self, meta: &ParamMeta, field: &DefField, out: &mut impl Write) -> Result {
+    fn gen_field_doc(
+        &self,
+        meta: &ParamMeta,
+        field: &DefField,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
         if let Some(wiki) = meta
             .fields
             .get(&field.field_def.name)
             .and_then(|f| f.wiki.as_deref())
         {
-            self.gen_doc_comment(wiki, out)?;
+            self.gen_doc_comment(wiki, config, out)?;
+        }
+        if let Some(meta_field) = meta.fields.get(&field.field_def.name) {
+            self.gen_ref_doc(meta_field, config, out)?;
         }
         if let Some(dname) = &field.display_name {
-            self.gen_doc_comment(format!("### Display Name\n{}", dname), out)?;
+            self.gen_doc_comment(format!("### Display Name\n{}", dname), config, out)?;
         }
         if let Some(desc) = &field.description {
-            self.gen_doc_comment(format!("### Description\n{}", desc), out)?;
+            self.gen_doc_comment(format!("### Description\n{}", desc), config, out)?;
         }
         if let Some(min) = field.minimum {
-            self.gen_doc_comment(format!("### Minimum\n{}", min), out)?;
+            self.gen_doc_comment(format!("### Minimum\n{}", min), config, out)?;
         }
         if let Some(max) = field.maximum {
-            self.gen_doc_comment(format!("### Maximum\n{}", max), out)?;
+            self.gen_doc_comment(format!("### Maximum\n{}", max), config, out)?;
         }
 
         Ok(())
     }
 
+    fn gen_ref_doc(
+        &self,
+        field: &ParamMetaField,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        if !field.refs.is_empty() {
+            let refs: Vec<_> = field
+                .refs
+                .iter()
+                .map(|r| match &r.condition {
+                    Some((cond_field, value)) => {
+                        format!("- `{}` if `{}` is {}", r.param, cond_field, value)
+                    }
+                    None => format!("- `{}`", r.param),
+                })
+                .collect();
+            self.gen_doc_comment(format!("### References\n{}", refs.join("\n")), config, out)?;
+        }
+        if let Some(vref) = &field.vref {
+            self.gen_doc_comment(format!("### Virtual Reference\n`{}`", vref), config, out)?;
+        }
+        if let Some(fmg) = &field.fmg_ref {
+            self.gen_doc_comment(format!("### Text\nFMG `{}`", fmg), config, out)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the enum named by the Meta of a field, or by its paramdef if the Meta does not
+    /// name one. Enums of the Meta file take precedence over the shared Tdfs enums.
+    fn resolve_field_enum(
+        &self,
+        meta: &'a ParamMeta,
+        field: &DefField,
+        config: &CodegenParams,
+    ) -> std::result::Result<Option<&'a ParamMetaEnum>, EnumDiagnosticKind> {
+        let Some(name) = meta
+            .fields
+            .get(&field.field_def.name)
+            .and_then(|m| m.enum_name.as_deref())
+            .or(field.enum_name.as_deref())
+        else {
+            return Ok(None);
+        };
+        let e = meta
+            .enums
+            .iter()
+            .find(|e| e.name == name)
+            .or_else(|| self.def_db.tdf_enum(name))
+            .ok_or(EnumDiagnosticKind::Missing)?;
+        let int_type = e.base_type.rust_type();
+        if int_type != field.field_def.base_type.rust_type() {
+            return Err(EnumDiagnosticKind::TypeMismatch {
+                enum_type: e.base_type,
+                field_type: field.field_def.base_type,
+            });
+        }
+        // Newtype enums hold any default, but Rust enums must have a variant for it
+        let default = default_int(field.default_value().unwrap_or(0.0), int_type);
+        if !is_newtype_enum(e, config) && !e.options.iter().any(|o| o.value == default) {
+            return Err(EnumDiagnosticKind::InvalidDefault { default });
+        }
+        Ok(Some(e))
+    }
+
+    /// Returns the Meta enum used as the type of a field, if any.
+    fn field_enum(
+        &self,
+        def: &Paramdef,
+        meta: &'a ParamMeta,
+        field: &DefField,
+        config: &CodegenParams,
+    ) -> Option<&'a ParamMetaEnum> {
+        if !config.field_enums || is_be_wrapped(def, field, config) {
+            return None;
+        }
+        self.resolve_field_enum(meta, field, config).ok().flatten()
+    }
+
+    /// Lists the fields of the target paramdefs which name an enum that cannot be used as their
+    /// type with the given config. Such fields are generated with their raw integer type.
+    pub fn enum_diagnostics(&self, config: &CodegenParams) -> Vec<EnumDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for (&param, def) in &self.param_types {
+            let Some(meta) = self.def_db.def_meta(param) else {
+                continue;
+            };
+            for field in def.fields.iter() {
+                if let Err(kind) = self.resolve_field_enum(meta, field, config) {
+                    let enum_name = meta
+                        .fields
+                        .get(&field.field_def.name)
+                        .and_then(|m| m.enum_name.clone())
+                        .or(field.enum_name.clone())
+                        .unwrap();
+                    diagnostics.push(EnumDiagnostic {
+                        param: param.to_owned(),
+                        field: field.field_def.name.clone(),
+                        enum_name,
+                        kind,
+                    });
+                }
+            }
+        }
+        diagnostics.sort_unstable_by(|a, b| (&a.param, &a.field).cmp(&(&b.param, &b.field)));
+        diagnostics
+    }
+
+    /// Returns the ID newtype used as the type of a field referencing another param or an FMG,
+    /// along with its doc comment.
+    fn field_ref_id(
+        &self,
+        def: &Paramdef,
+        meta: &ParamMeta,
+        field: &DefField,
+        config: &CodegenParams,
+    ) -> Option<(String, String)> {
+        if !config.ref_ids || self.field_enum(def, meta, field, config).is_some() {
+            return None;
+        }
+        let meta_field = meta.fields.get(&field.field_def.name)?;
+        if let Some(param) = meta_field.ref_param() {
+            return Some((
+                format!("{}Id", param),
+                format!("Row ID of the `{}` param.", param),
+            ));
+        }
+        let fmg = meta_field.fmg_ref.as_deref()?;
+        Some((
+            format!("{}FmgId", fmg),
+            format!("ID of a text entry of the `{}` FMG.", fmg),
+        ))
+    }
+
+    /// Collects the ID newtypes used by the fields of a param.
+    fn param_ref_ids(&self, name: &str, config: &CodegenParams) -> BTreeMap<String, String> {
+        let meta = self.def_db.def_meta(name).unwrap();
+        self.param_defs(name, config)
+            .into_iter()
+            .flat_map(|def| def.fields.iter().map(move |f| (def, f)))
+            .filter_map(|(def, f)| self.field_ref_id(def, meta, f, config))
+            .collect()
+    }
+
+    fn gen_ref_id(
+        &self,
+        name: &str,
+        doc: &str,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        self.gen_doc_comment(doc, config, out)?;
+        writeln!(
+            out,
+            "#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct {}<T>(pub T);",
+            name
+        )?;
+        if config.serde {
+            writeln!(
+                out,
+                r"impl<T: ::paramdef_codegen::serde::Serialize> ::paramdef_codegen::serde::Serialize for {name}<T> {{
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {{
+self.0.serialize(serializer)
+}}
+}}
+impl<'de, T: ::paramdef_codegen::serde::Deserialize<'de>> ::paramdef_codegen::serde::Deserialize<'de> for {name}<T> {{
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {{
+T::deserialize(deserializer).map(Self)
+}}
+}}"
+            )?;
+        }
+        Ok(())
+    }
+
     fn type_vis(&self, t: DefBaseType, config: &CodegenParams) -> &str {
         if config.private_dummy8 && t == DefBaseType::Dummy8 {
             ""
@@ -126,31 +536,501 @@ impl<'a> RustCodegen<'a> {
         config: &CodegenParams,
         out: &mut impl std::fmt::Write,
     ) -> Result {
-        let def = self.param_types[name];
-        let meta = self.def_db.def_meta(name).unwrap();
+        if config.field_enums {
+            let meta = self.def_db.def_meta(name).unwrap();
+            for e in meta.enums.iter() {
+                self.gen_enum(e, config, out)?;
+            }
+            for e in self.used_tdf_enums(name, config) {
+                self.gen_enum(e, config, out)?;
+            }
+        }
+        for (id_type, doc) in self.param_ref_ids(name, config) {
+            self.gen_ref_id(&id_type, &doc, config, out)?;
+        }
+        self.gen_param_structs(name, config, out)?;
+        if config.name_enums {
+            for game_param in self.named_game_params(name) {
+                self.gen_row_names(game_param, config, out)?;
+            }
+        }
+        Ok(())
+    }
 
-        let enums: HashMap<_, _> = meta.enums.iter().map(|e| (e.name.as_str(), e)).collect();
+    /// Generates several paramdefs in a single file. As with [`Self::gen_all`], the enums shared
+    /// by the params and the ID newtypes are only generated once, and each param is generated in
+    /// its own module whose struct and row name modules are re-exported. A single param is
+    /// generated like with [`Self::gen_paramdef`].
+    ///
+    /// # Panics
+    /// if one of `names` is not a paramdef found in the paramdex DB passed on construction.
+    pub fn gen_params(
+        &self,
+        names: &[&str],
+        config: &CodegenParams,
+        out: &mut impl std::fmt::Write,
+    ) -> Result {
+        let names: Vec<_> = names
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if let [name] = names[..] {
+            return self.gen_paramdef(name, config, out);
+        }
+
+        let shared_enums = self.shared_enums(&names);
         if config.field_enums {
+            let used_tdf_enums: BTreeSet<_> = names
+                .iter()
+                .flat_map(|n| self.used_tdf_enums(n, config))
+                .map(|e| e.name.as_str())
+                .collect();
+            for e in shared_enums.values().filter(|e| {
+                self.def_db.tdf_enum(&e.name) != Some(e) || used_tdf_enums.contains(e.name.as_str())
+            }) {
+                self.gen_enum(e, config, out)?;
+            }
+        }
+        let ref_ids: BTreeMap<_, _> = names
+            .iter()
+            .flat_map(|n| self.param_ref_ids(n, config))
+            .collect();
+        for (id_type, doc) in &ref_ids {
+            self.gen_ref_id(id_type, doc, config, out)?;
+        }
+
+        for name in names {
+            let module_name = module_name(name);
+            writeln!(out, "pub mod {} {{", module_name)?;
+            self.gen_param_module(name, config, &shared_enums, "super", "super", out)?;
+            writeln!(out, "}}")?;
+            self.gen_module_exports(name, &module_name, config, out)?;
+        }
+        Ok(())
+    }
+
+    /// Generates a module tree containing every paramdef of the target version in `out_dir`.
+    ///
+    /// Each param is written to its own module file, and `root_file_name` (usually `mod.rs` or
+    /// `lib.rs`) declares all of them and re-exports the param structs. The Tdfs enums and the Meta
+    /// enums which have the same definition in every Meta file they appear in are only generated
+    /// once, in the `enums` module.
+    pub fn gen_all(
+        &self,
+        config: &CodegenParams,
+        out_dir: impl AsRef<Path>,
+        root_file_name: &str,
+    ) -> io::Result<()> {
+        let out_dir = out_dir.as_ref();
+        fs::create_dir_all(out_dir)?;
+
+        let mut names: Vec<_> = self
+            .param_types
+            .keys()
+            .copied()
+            .filter(|n| self.def_db.def_meta(n).is_some())
+            .collect();
+        names.sort_unstable();
+
+        let shared_enums = self.shared_enums(&names);
+        let mut root = String::new();
+
+        if config.field_enums && !shared_enums.is_empty() {
+            let mut enums_mod = "#![allow(non_camel_case_types)]\n".to_owned();
+            for e in shared_enums.values() {
+                self.gen_enum(e, config, &mut enums_mod)
+                    .map_err(io::Error::other)?;
+            }
+            fs::write(out_dir.join("enums.rs"), enums_mod)?;
+            writeln!(root, "pub mod enums;").map_err(io::Error::other)?;
+        }
+
+        let ref_ids: BTreeMap<_, _> = names
+            .iter()
+            .flat_map(|n| self.param_ref_ids(n, config))
+            .collect();
+        if !ref_ids.is_empty() {
+            let mut ids_mod = "#![allow(non_camel_case_types)]\n".to_owned();
+            for (id_type, doc) in &ref_ids {
+                self.gen_ref_id(id_type, doc, config, &mut ids_mod)
+                    .map_err(io::Error::other)?;
+            }
+            fs::write(out_dir.join("ref_ids.rs"), ids_mod)?;
+            writeln!(root, "pub mod ref_ids;").map_err(io::Error::other)?;
+        }
+
+        for name in names {
+            let module_name = module_name(name);
+            let mut code = String::new();
+            self.gen_param_module(
+                name,
+                config,
+                &shared_enums,
+                "super::enums",
+                "super::ref_ids",
+                &mut code,
+            )
+            .map_err(io::Error::other)?;
+            fs::write(out_dir.join(format!("{}.rs", module_name)), code)?;
+
+            writeln!(root, "pub mod {};", module_name).map_err(io::Error::other)?;
+            self.gen_module_exports(name, &module_name, config, &mut root)
+                .map_err(io::Error::other)?;
+        }
+
+        fs::write(out_dir.join(root_file_name), root)
+    }
+
+    /// Returns the Tdfs enums used by the fields of a param, sorted by name.
+    fn used_tdf_enums(&self, name: &str, config: &CodegenParams) -> Vec<&'a ParamMetaEnum> {
+        let meta = self.def_db.def_meta(name).unwrap();
+        let used: BTreeMap<_, _> = self
+            .param_defs(name, config)
+            .into_iter()
+            .flat_map(|def| def.fields.iter().map(move |f| (def, f)))
+            .filter_map(|(def, f)| self.field_enum(def, meta, f, config))
+            .filter(|e| !meta.enums.contains(e))
+            .map(|e| (&e.name, e))
+            .collect();
+        used.into_values().collect()
+    }
+
+    /// Collects the enums of the given params which have a single definition across all Meta files,
+    /// as well as all the Tdfs enums. Meta enums named like a Tdfs enum are never shared.
+    fn shared_enums(&self, names: &[&str]) -> BTreeMap<&'a str, &'a ParamMetaEnum> {
+        let mut enums: BTreeMap<&str, Option<&ParamMetaEnum>> = BTreeMap::new();
+        for meta in names.iter().filter_map(|n| self.def_db.def_meta(n)) {
             for e in meta.enums.iter() {
-                self.gen_enum(e, out)?;
+                enums
+                    .entry(&e.name)
+                    .and_modify(|shared| {
+                        if shared.is_some_and(|s| s != e) {
+                            *shared = None;
+                        }
+                    })
+                    .or_insert(Some(e));
+            }
+        }
+        for e in self.def_db.tdf_enums().values() {
+            enums.insert(&e.name, Some(e));
+        }
+        enums
+            .into_iter()
+            .filter_map(|(name, e)| Some((name, e?)))
+            .collect()
+    }
+
+    /// Generates the contents of the module of a param, importing the shared enums from
+    /// `enums_path` and the ID newtypes from `ref_ids_path`.
+    fn gen_param_module(
+        &self,
+        name: &str,
+        config: &CodegenParams,
+        shared_enums: &BTreeMap<&str, &ParamMetaEnum>,
+        enums_path: &str,
+        ref_ids_path: &str,
+        out: &mut impl Write,
+    ) -> Result {
+        writeln!(out, "#![allow(non_camel_case_types, non_snake_case)]")?;
+
+        if config.field_enums {
+            let meta = self.def_db.def_meta(name).unwrap();
+            let used_shared: BTreeSet<_> = self
+                .param_defs(name, config)
+                .into_iter()
+                .flat_map(|def| def.fields.iter().map(move |f| (def, f)))
+                .filter_map(|(def, f)| self.field_enum(def, meta, f, config))
+                .filter(|&e| shared_enums.get(e.name.as_str()) == Some(&e))
+                .map(|e| e.name.as_str())
+                .collect();
+
+            if !used_shared.is_empty() {
+                let imports: Vec<_> = used_shared.into_iter().collect();
+                writeln!(out, "use {}::{{{}}};", enums_path, imports.join(", "))?;
+            }
+            for e in meta
+                .enums
+                .iter()
+                .filter(|&e| shared_enums.get(e.name.as_str()) != Some(&e))
+            {
+                self.gen_enum(e, config, out)?;
+            }
+        }
+        let ref_ids = self.param_ref_ids(name, config);
+        if !ref_ids.is_empty() {
+            let imports: Vec<_> = ref_ids.into_keys().collect();
+            writeln!(out, "use {}::{{{}}};", ref_ids_path, imports.join(", "))?;
+        }
+        self.gen_param_structs(name, config, out)?;
+        if config.name_enums {
+            for game_param in self.named_game_params(name) {
+                self.gen_row_names(game_param, config, out)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-exports the param struct and the row name modules of a param from its module.
+    fn gen_module_exports(
+        &self,
+        name: &str,
+        module_name: &str,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        writeln!(
+            out,
+            "pub use {}::{};",
+            module_name, &self.param_types[name].param_type
+        )?;
+        if config.name_enums {
+            for game_param in self.named_game_params(name) {
+                writeln!(out, "pub use {}::{};", module_name, game_param)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the DefsPatch versions of a paramdef along with their definition, the base
+    /// paramdef being version 0.
+    fn def_versions(&self, name: &str) -> Vec<(usize, &'a Paramdef)> {
+        self.def_db
+            .def_versions(name)
+            .filter_map(|v| Some((v, self.def_db.def(name, v)?)))
+            .collect()
+    }
+
+    /// Returns the paramdefs for which a param struct is generated.
+    fn param_defs(&self, name: &str, config: &CodegenParams) -> Vec<&'a Paramdef> {
+        match config.versions {
+            true => self
+                .def_versions(name)
+                .into_iter()
+                .map(|(_, def)| def)
+                .collect(),
+            false => vec![self.param_types[name]],
+        }
+    }
+
+    /// Generates the param struct of the target version, or a module per version and the
+    /// conversions between them if [`CodegenParams::versions`] is set.
+    fn gen_param_structs(
+        &self,
+        name: &str,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        let target = self.param_types[name];
+        if !config.versions {
+            return self.gen_param_struct(name, target, config, out);
+        }
+
+        let versions = self.def_versions(name);
+        for &(version, def) in &versions {
+            match version {
+                0 => self.gen_doc_comment("Base layout of the param.", config, out)?,
+                _ => self.gen_doc_comment(
+                    format!("Layout of the param from DefsPatch version {}.", version),
+                    config,
+                    out,
+                )?,
             }
+            writeln!(
+                out,
+                "pub mod v{} {{\n#[allow(unused_imports)]\nuse super::*;",
+                version
+            )?;
+            self.gen_param_struct(name, def, config, out)?;
+            writeln!(out, "}}")?;
         }
+        let (target_version, _) = versions
+            .iter()
+            .find(|(_, def)| std::ptr::eq(*def, target))
+            .unwrap();
+        writeln!(out, "pub use v{}::{};", target_version, &target.param_type)?;
+
+        for pair in versions.windows(2) {
+            self.gen_version_conversion(name, pair[0], pair[1], config, out)?;
+            self.gen_version_conversion(name, pair[1], pair[0], config, out)?;
+        }
+        Ok(())
+    }
+
+    /// Generates a `TryFrom` conversion between the param structs of two versions. Fields are
+    /// matched by name, and fields missing from the source version or whose type changed keep
+    /// their default value.
+    fn gen_version_conversion(
+        &self,
+        name: &str,
+        (from_version, from_def): (usize, &Paramdef),
+        (to_version, to_def): (usize, &Paramdef),
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        let meta = self.def_db.def_meta(name).unwrap();
+        let from_fields: HashMap<_, _> = from_def
+            .fields
+            .iter()
+            .zip(field_idents(from_def))
+            .map(|(f, ident)| (f.field_def.name.as_str(), (f, ident)))
+            .collect();
 
-        if config.docs {
-            if let Some(wiki) = &meta.self_desc {
-                self.gen_doc_comment(wiki, out)?;
+        writeln!(
+            out,
+            "impl TryFrom<&v{from_version}::{from_type}> for v{to_version}::{to_type} {{
+type Error = ::std::io::Error;
+fn try_from(value: &v{from_version}::{from_type}) -> ::std::io::Result<Self> {{
+let mut row = Self::default();",
+            from_type = &from_def.param_type,
+            to_type = &to_def.param_type
+        )?;
+        for (f, ident) in to_def.fields.iter().zip(field_idents(to_def)) {
+            if f.field_def.base_type == DefBaseType::Dummy8 {
+                continue;
             }
+            let Some(&(from_f, ref from_ident)) = from_fields.get(f.field_def.name.as_str()) else {
+                continue;
+            };
+            let to_types = self.field_types(to_def, meta, f, config);
+            let from_types = self.field_types(from_def, meta, from_f, config);
+
+            let compatible = to_types.rust_type == from_types.rust_type
+                && match (f.field_def.modifier, from_f.field_def.modifier) {
+                    (DefTypeModifier::Array(a), DefTypeModifier::Array(b)) => a == b,
+                    (DefTypeModifier::Array(_), _) | (_, DefTypeModifier::Array(_)) => is_string(f),
+                    _ => true,
+                };
+            if !compatible {
+                writeln!(out, "// {} changed type and keeps its default value", ident)?;
+                continue;
+            }
+
+            let value = match from_f.field_def.modifier {
+                DefTypeModifier::Bitfield(_) => format!("value.{}_get()", from_ident),
+                _ => format!("value.{}", from_ident),
+            };
+            match f.field_def.modifier {
+                DefTypeModifier::Bitfield(_) if to_types.is_bool => {
+                    writeln!(out, "row.{}_set({});", ident, value)?
+                }
+                DefTypeModifier::Bitfield(_) => writeln!(out, "row.{}_set({})?;", ident, value)?,
+                _ => writeln!(out, "row.{} = {};", ident, value)?,
+            }
+        }
+        writeln!(out, "Ok(row)\n}}\n}}")
+    }
+
+    /// Returns the sorted names of the regulation params using the given paramdef which have row
+    /// names in the paramdex.
+    fn named_game_params(&self, name: &str) -> Vec<&'a str> {
+        let param_type = &self.param_types[name].param_type;
+        let mut params: Vec<_> = self
+            .game_params
+            .iter()
+            .filter(|(n, p)| {
+                &p.header.param_type == param_type && self.def_db.row_id_names(n).is_some()
+            })
+            .map(|(&n, _)| n)
+            .collect();
+        params.sort_unstable();
+        params
+    }
+
+    /// Generates a module named after a regulation param containing a constant for each named row
+    /// ID, as well as `phf` maps from row IDs to names and back.
+    fn gen_row_names(
+        &self,
+        game_param: &str,
+        config: &CodegenParams,
+        out: &mut impl Write,
+    ) -> Result {
+        let names = self.def_db.row_id_names(game_param).unwrap();
+        let mut rows: Vec<_> = names
+            .iter()
+            .map(|(&id, name)| (id, name.trim()))
+            .filter(|(_, name)| !name.is_empty())
+            .collect();
+        rows.sort_unstable();
+
+        self.gen_doc_comment(
+            format!(
+                "Row IDs and names of the `{}` param, from the paramdex names.",
+                game_param
+            ),
+            config,
+            out,
+        )?;
+        writeln!(out, "pub mod {} {{", game_param)?;
+
+        writeln!(out, "pub mod ids {{")?;
+        for ((id, name), ident) in rows.iter().zip(row_const_idents(&rows)) {
+            self.gen_doc_comment(name, config, out)?;
+            writeln!(out, "pub const {}: u32 = {};", ident, id)?;
+        }
+        writeln!(out, "}}")?;
+
+        let mut names_map = phf_codegen::Map::new();
+        let mut ids_map = phf_codegen::Map::new();
+        let mut seen = HashSet::new();
+        names_map.phf_path("::paramdef_codegen::phf");
+        ids_map.phf_path("::paramdef_codegen::phf");
+        for &(id, name) in &rows {
+            names_map.entry(id, &format!("{:?}", name));
+            if seen.insert(name) {
+                ids_map.entry(name, &id.to_string());
+            }
+        }
+        self.gen_doc_comment("Row names by ID.", config, out)?;
+        writeln!(
+            out,
+            "pub static NAMES: ::paramdef_codegen::phf::Map<u32, &'static str> = {};",
+            names_map.build()
+        )?;
+        self.gen_doc_comment(
+            "Row IDs by name. If several rows have the same name, this is the lowest of their IDs.",
+            config,
+            out,
+        )?;
+        writeln!(
+            out,
+            "pub static IDS: ::paramdef_codegen::phf::Map<&'static str, u32> = {};",
+            ids_map.build()
+        )?;
+        writeln!(out, "}}")
+    }
+
+    fn gen_param_struct(
+        &self,
+        name: &str,
+        def: &Paramdef,
+        config: &CodegenParams,
+        out: &mut impl std::fmt::Write,
+    ) -> Result {
+        let meta = self.def_db.def_meta(name).unwrap();
+        let big_endian = config.big_endian || def.big_endian;
+        let endian = if big_endian { "be" } else { "le" };
+
+        if let Some(wiki) = &meta.self_desc {
+            self.gen_doc_comment(wiki, config, out)?;
         }
         writeln!(out, "#[repr(C)]\npub struct {} {{", &def.param_type)?;
 
         let mut impl_code = format!("impl {} {{\n", &def.param_type);
+        let mut valid_checks = Vec::new();
+        let mut layout_checks = Vec::new();
+        let mut field_infos = Vec::new();
+        let mut serde_fields = Vec::new();
+        let mut default_fields = Vec::new();
+        let mut default_bitfields = Vec::new();
         let mut field_doc = String::new();
         let mut last_bitfield_offset = None;
         let mut pad_id = 0;
 
-        for f in def.fields.iter() {
+        for (f, field_name) in def.fields.iter().zip(field_idents(def)) {
             field_doc.clear();
-            self.gen_field_doc(meta, f, &mut field_doc)?;
+            self.gen_field_doc(meta, f, config, &mut field_doc)?;
 
             if !f.field_def.modifier.is_bitfield() {
                 if let Some(ofs) = last_bitfield_offset {
@@ -158,25 +1038,116 @@ impl<'a> RustCodegen<'a> {
                     let pad_bytes = (f.bit_offset.unwrap() - ofs) / 8;
                     pad_id += 1;
                     writeln!(out, "_bitfield{}: [u8; {}],", pad_id, pad_bytes)?;
+                    default_fields.push(format!("_bitfield{}: [0; {}]", pad_id, pad_bytes));
                 }
             } else if last_bitfield_offset.is_none() {
                 last_bitfield_offset = Some(f.bit_offset.unwrap());
             }
 
-            let rust_type = if config.field_enums {
-                if let Some((name, _)) = meta
-                    .fields
-                    .get(&f.field_def.name)
-                    .and_then(|m| m.enum_name.as_deref())
-                    .and_then(|n| enums.get_key_value(n))
-                {
-                    name
-                } else {
-                    f.field_def.base_type.rust_type().to_str()
+            if !f.field_def.modifier.is_bitfield() {
+                layout_checks.push(format!(
+                    "const _: () = assert!(::std::mem::offset_of!({}, {}) == {});",
+                    &def.param_type,
+                    &field_name,
+                    f.bit_offset.unwrap() / 8
+                ));
+            }
+
+            let FieldTypes {
+                int_type,
+                be_type,
+                field_enum,
+                ref_id,
+                is_bool,
+                rust_type,
+            } = self.field_types(def, meta, f, config);
+
+            if config.reflection {
+                field_infos.push(gen_field_info(f, &field_name, field_enum));
+            }
+            if config.serde && f.field_def.base_type != DefBaseType::Dummy8 {
+                serde_fields.push(gen_serde_field(f, &field_name, is_bool));
+            }
+
+            // Strings are wrapped in a single field, regardless of their length
+            if is_string(f) {
+                writeln!(
+                    out,
+                    "{}{}{}: {},",
+                    &field_doc,
+                    self.type_vis(f.field_def.base_type, config),
+                    &field_name,
+                    rust_type
+                )?;
+                default_fields.push(format!("{}: Default::default()", field_name));
+                continue;
+            }
+
+            let default_value = f.default_value().unwrap_or(0.0);
+            let default = default_expr(
+                default_value,
+                int_type,
+                be_type.as_deref(),
+                field_enum,
+                ref_id.clone(),
+                config,
+            );
+            match f.field_def.modifier {
+                DefTypeModifier::None => {
+                    default_fields.push(format!("{}: {}", field_name, default))
                 }
-            } else {
-                f.field_def.base_type.rust_type().to_str()
-            };
+                DefTypeModifier::Array(len) => {
+                    default_fields.push(format!("{}: [{}; {}]", field_name, default, len))
+                }
+                DefTypeModifier::Bitfield(_) if is_bool && default_value != 0.0 => {
+                    default_bitfields.push(format!("row.{}_set(true);", field_name))
+                }
+                DefTypeModifier::Bitfield(_) if default_value != 0.0 => {
+                    default_bitfields.push(format!("row.{}_set({}).unwrap();", field_name, default))
+                }
+                DefTypeModifier::Bitfield(_) => (),
+            }
+
+            // Enums do not accept every bit pattern, so they must be checked when casting row data
+            if let Some(e) = field_enum.filter(|e| !is_newtype_enum(e, config)) {
+                let size = int_type.size_bytes();
+                let read_int = |ofs: String, endian: &str| {
+                    format!(
+                        "{}::from_{}_bytes(data[{ofs}..{ofs} + {}].try_into().unwrap())",
+                        int_type, endian, size
+                    )
+                };
+                // Enum fields are read in native byte order, like they are stored in the struct,
+                // while bitfields are read like their accessors do
+                let offset = f.bit_offset.unwrap() / 8;
+                let check = match f.field_def.modifier {
+                    DefTypeModifier::None => {
+                        enum_check(&e.options, &read_int(offset.to_string(), "ne"))
+                    }
+                    DefTypeModifier::Array(len) => format!(
+                        "(0..{}).all(|i| {})",
+                        len,
+                        enum_check(
+                            &e.options,
+                            &read_int(format!("{} + i * {}", offset, size), "ne")
+                        )
+                    ),
+                    DefTypeModifier::Bitfield(width) => {
+                        let read_bit_offset = f.bit_offset.unwrap() & !(f.alignment_bits() - 1);
+                        let shift = f.bit_offset.unwrap() - read_bit_offset;
+                        let mask = ((1usize << width) - 1) << shift;
+                        let value = format!(
+                            "({} & {}{}) >> {}",
+                            read_int((read_bit_offset / 8).to_string(), endian),
+                            mask,
+                            int_type,
+                            shift
+                        );
+                        enum_check(&e.options, &value)
+                    }
+                };
+                valid_checks.push((field_name.clone(), check));
+            }
 
             match f.field_def.modifier {
                 DefTypeModifier::None => {
@@ -185,9 +1156,20 @@ impl<'a> RustCodegen<'a> {
                         "{}{}{}: {},",
                         &field_doc,
                         self.type_vis(f.field_def.base_type, config),
-                        &f.field_def.name,
+                        &field_name,
                         rust_type
                     )?;
+                    if is_bool {
+                        writeln!(
+                            &mut impl_code,
+                            r"{field_doc}pub fn {field_name}_get(&self) -> bool {{
+self.{field_name} != 0
+}}
+{field_doc}pub fn {field_name}_set(&mut self, value: bool) {{
+self.{field_name} = value as u8;
+}}"
+                        )?;
+                    }
                 }
                 DefTypeModifier::Array(len) => {
                     writeln!(
@@ -195,44 +1177,730 @@ impl<'a> RustCodegen<'a> {
                         "{}{}{}: [{}; {}],",
                         &field_doc,
                         self.type_vis(f.field_def.base_type, config),
-                        &f.field_def.name,
+                        &field_name,
                         rust_type,
                         len
                     )?;
                 }
                 DefTypeModifier::Bitfield(width) => {
-                    let field_name = &f.field_def.name;
-                    let base_int = f.field_def.base_type.rust_type().to_str();
+                    // Read and written as unsigned integers of the param's endianness within the
+                    // storage array of the bitfield group
+                    let storage_int = match int_type.size_bytes() {
+                        1 => "u8",
+                        2 => "u16",
+                        _ => "u32",
+                    };
+                    let group_offset = last_bitfield_offset.unwrap() / 8;
                     let read_bit_offset = f.bit_offset.unwrap() & !(f.alignment_bits() - 1);
-                    let read_offset = read_bit_offset / 8;
+                    let read_start = read_bit_offset / 8 - group_offset;
+                    let read_end = read_start + int_type.size_bytes();
+                    let storage =
+                        format!("self._bitfield{}[{}..{}]", pad_id + 1, read_start, read_end);
                     let shift = f.bit_offset.unwrap() - read_bit_offset;
-                    let mask = ((1usize << width) - 1) << shift;
+                    let max = (1usize << width) - 1;
+                    let mask = max << shift;
+
+                    let (from_bits, to_bits) = match (field_enum, &ref_id) {
+                        _ if is_bool => ("bits != 0".to_owned(), "value as _"),
+                        (Some(e), _) if !is_newtype_enum(e, config) => (
+                            format!("{}::try_from(bits as {}).unwrap()", rust_type, int_type),
+                            "value as _",
+                        ),
+                        (Some(_), _) | (None, Some(_)) => (
+                            format!(
+                                "{}(bits as {})",
+                                rust_type.split('<').next().unwrap(),
+                                int_type
+                            ),
+                            "value.0 as _",
+                        ),
+                        (None, None) => (format!("bits as {}", int_type), "value as _"),
+                    };
 
                     writeln!(
                         &mut impl_code,
-                        r"
-                    {field_doc}pub fn {field_name}_get(&self) -> {rust_type} {{
-                        unsafe {{
-                            let mem = *((self as *const Self as *const u8 as usize + {read_offset}) as *const {base_int});
-                            std::mem::transmute((mem & {mask}{base_int}) >> {shift})
-                        }}
-                    }}
-                    {field_doc}pub fn {field_name}_set(&mut self, value: {rust_type}) {{
-                        unsafe {{
-                            let bi : {base_int} = std::mem::transmute(value);
-                            let mem_ptr = (self as *const Self as *const u8 as usize + {read_offset}) as *mut {base_int};
-                            *mem_ptr = *mem_ptr & !{mask}{base_int} | (bi << {shift});
-                        }}
-                    }}
-                    {field_doc}pub fn {field_name}_update(&mut self, f: impl Fn({rust_type}) -> {rust_type}) {{
-                        self.{field_name}_set(f(self.{field_name}_get()));
-                    }}"
+                        r"{field_doc}pub fn {field_name}_get(&self) -> {rust_type} {{
+let storage = {storage_int}::from_{endian}_bytes({storage}.try_into().unwrap());
+let bits = (storage & {mask}{storage_int}) >> {shift};
+{from_bits}
+}}"
                     )?;
+                    if is_bool {
+                        writeln!(
+                            &mut impl_code,
+                            r"{field_doc}pub fn {field_name}_set(&mut self, value: bool) {{
+let bits: {storage_int} = {to_bits};"
+                        )?;
+                    } else {
+                        let mut errors_doc = String::new();
+                        self.gen_doc_comment(
+                            format!("### Errors\nIf the value does not fit in {} bits.", width),
+                            config,
+                            &mut errors_doc,
+                        )?;
+                        writeln!(
+                            &mut impl_code,
+                            r"{field_doc}{errors_doc}pub fn {field_name}_set(&mut self, value: {rust_type}) -> ::std::io::Result<()> {{
+let bits: {storage_int} = {to_bits};
+if bits & !{max}{storage_int} != 0 {{
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!({:?}, value),
+));
+}}",
+                            format!(
+                                "{{:?}} does not fit in the {} bits of {}",
+                                width, field_name
+                            )
+                        )?;
+                    }
+                    writeln!(
+                        &mut impl_code,
+                        r"let storage = {storage_int}::from_{endian}_bytes({storage}.try_into().unwrap());
+{storage}.copy_from_slice(&(storage & !{mask}{storage_int} | bits << {shift}).to_{endian}_bytes());{}
+}}",
+                        if is_bool { "" } else { "\nOk(())" }
+                    )?;
+                    if is_bool {
+                        writeln!(
+                            &mut impl_code,
+                            r"{field_doc}pub fn {field_name}_update(&mut self, f: impl Fn(bool) -> bool) {{
+self.{field_name}_set(f(self.{field_name}_get()));
+}}"
+                        )?;
+                    } else {
+                        writeln!(
+                            &mut impl_code,
+                            r"{field_doc}pub fn {field_name}_update(&mut self, f: impl Fn({rust_type}) -> {rust_type}) -> ::std::io::Result<()> {{
+self.{field_name}_set(f(self.{field_name}_get()))
+}}"
+                        )?;
+                    }
                 }
             }
         }
 
+        if let (Some(ofs), Some(size)) = (last_bitfield_offset, def.size_bytes) {
+            writeln!(out, "_bitfield{}: [u8; {}],", pad_id + 1, size - ofs / 8)?;
+            default_fields.push(format!("_bitfield{}: [0; {}]", pad_id + 1, size - ofs / 8));
+        }
         writeln!(out, "}}")?;
-        writeln!(out, "{}}}", &impl_code)
+        writeln!(out, "{}}}", &impl_code)?;
+
+        // New rows start from the default values of the paramdef
+        write!(
+            out,
+            "impl Default for {} {{\nfn default() -> Self {{\n",
+            &def.param_type
+        )?;
+        let init = format!("Self {{\n{}\n}}", default_fields.join(",\n"));
+        if default_bitfields.is_empty() {
+            writeln!(out, "{}", init)?;
+        } else {
+            writeln!(
+                out,
+                "let mut row = {};\n{}\nrow",
+                init,
+                default_bitfields.join("\n")
+            )?;
+        }
+        writeln!(out, "}}\n}}")?;
+
+        // Make sure rustc agrees with the offsets computed from the paramdef
+        if let Some(size) = def.size_bytes {
+            writeln!(
+                out,
+                "const _: () = assert!(::std::mem::size_of::<{}>() == {});",
+                &def.param_type, size
+            )?;
+        }
+        for check in &layout_checks {
+            writeln!(out, "{}", check)?;
+        }
+
+        self.gen_row_casts(&def.param_type, big_endian, &valid_checks, out)?;
+
+        if config.reflection {
+            writeln!(
+                out,
+                "impl ::paramdef_codegen::reflect::ParamFields for {} {{
+const FIELDS: &'static [::paramdef_codegen::reflect::FieldInfo] = &[
+{}
+];
+}}",
+                &def.param_type,
+                field_infos.join(",\n")
+            )?;
+        }
+        if config.serde {
+            self.gen_row_serde(&def.param_type, &serde_fields, out)?;
+        }
+        Ok(())
+    }
+
+    /// Computes the types used to generate a param struct field.
+    fn field_types(
+        &self,
+        def: &Paramdef,
+        meta: &'a ParamMeta,
+        f: &DefField,
+        config: &CodegenParams,
+    ) -> FieldTypes<'a> {
+        let int_type = f.field_def.base_type.rust_type();
+        let be_type = is_be_wrapped(def, f, config).then(|| {
+            format!(
+                "::paramdef_codegen::endian::{}Be",
+                int_type.to_str().to_uppercase()
+            )
+        });
+        let field_enum = self.field_enum(def, meta, f, config);
+        let ref_id = self
+            .field_ref_id(def, meta, f, config)
+            .map(|(id_type, _)| id_type);
+        // Bool fields keep their integer storage, but get accessors taking and returning bool
+        let is_bool = field_enum.is_none()
+            && ref_id.is_none()
+            && meta
+                .fields
+                .get(&f.field_def.name)
+                .is_some_and(|m| m.is_bool)
+            && match f.field_def.modifier {
+                DefTypeModifier::None => int_type == DefBaseRustType::U8,
+                DefTypeModifier::Array(_) => false,
+                DefTypeModifier::Bitfield(_) => true,
+            };
+
+        let str_type = match f.field_def.base_type {
+            DefBaseType::Fixstr => Some("FixedStr"),
+            DefBaseType::FixstrW if config.big_endian || def.big_endian => Some("FixedWStrBe"),
+            DefBaseType::FixstrW => Some("FixedWStr"),
+            _ => None,
+        };
+        let rust_type = match (field_enum, &ref_id) {
+            _ if str_type.is_some() => {
+                let len = match f.field_def.modifier {
+                    DefTypeModifier::Array(len) => len,
+                    _ => 1,
+                };
+                format!(
+                    "::paramdef_codegen::fixed_str::{}<{}>",
+                    str_type.unwrap(),
+                    len
+                )
+            }
+            _ if is_bool && f.field_def.modifier.is_bitfield() => "bool".to_owned(),
+            (Some(e), _) => e.name.clone(),
+            (None, Some(id_type)) => {
+                format!(
+                    "{}<{}>",
+                    id_type,
+                    be_type.as_deref().unwrap_or(int_type.to_str())
+                )
+            }
+            (None, None) => be_type.clone().unwrap_or_else(|| int_type.to_string()),
+        };
+
+        FieldTypes {
+            int_type,
+            be_type,
+            field_enum,
+            ref_id,
+            is_bool,
+            rust_type,
+        }
+    }
+
+    /// Generates the serde implementations of a param struct from the `(name, value, setter)`
+    /// triples of its fields.
+    fn gen_row_serde(
+        &self,
+        struct_name: &str,
+        fields: &[(String, String, String)],
+        out: &mut impl Write,
+    ) -> Result {
+        writeln!(
+            out,
+            r#"impl ::paramdef_codegen::serde::Serialize for {struct_name} {{
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {{
+use ::paramdef_codegen::serde::ser::SerializeStruct;
+let mut row = serializer.serialize_struct("{struct_name}", {})?;"#,
+            fields.len()
+        )?;
+        for (name, value, _) in fields {
+            writeln!(out, "row.serialize_field({:?}, {})?;", name, value)?;
+        }
+        writeln!(
+            out,
+            r"row.end()
+}}
+}}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for {struct_name} {{
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {{
+let mut row = Self::default();
+::paramdef_codegen::row_serde::deserialize_into(&mut row, deserializer)?;
+Ok(row)
+}}
+}}
+impl ::paramdef_codegen::row_serde::DeserializeFields for {struct_name} {{
+const FIELD_NAMES: &'static [&'static str] = &[{}];
+fn deserialize_field<'de, A: ::paramdef_codegen::serde::de::MapAccess<'de>>(&mut self, name: &str, map: &mut A) -> ::std::result::Result<bool, A::Error> {{
+match name {{",
+            fields
+                .iter()
+                .map(|(name, _, _)| format!("{:?}", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        for (name, _, setter) in fields {
+            writeln!(out, "{:?} => {},", name, setter)?;
+        }
+        writeln!(out, "_ => return Ok(false),\n}}\nOk(true)\n}}\n}}")
+    }
+
+    /// Generates the [`ParamRow`] implementation of a param struct, and a `TryFrom<&Row>` cast.
+    fn gen_row_casts(
+        &self,
+        struct_name: &str,
+        big_endian: bool,
+        valid_checks: &[(String, String)],
+        out: &mut impl Write,
+    ) -> Result {
+        write!(
+            out,
+            "unsafe impl ::paramdef_codegen::param::ParamRow for {} {{",
+            struct_name
+        )?;
+        if big_endian {
+            write!(out, "\nconst BIG_ENDIAN: bool = true;\n")?;
+        }
+        if !valid_checks.is_empty() {
+            writeln!(
+                out,
+                "\nfn invalid_field(data: &[u8]) -> ::std::option::Option<&'static str> {{"
+            )?;
+            for (field_name, check) in valid_checks {
+                writeln!(
+                    out,
+                    "if !{} {{\nreturn Some(\"{}\");\n}}",
+                    check, field_name
+                )?;
+            }
+            writeln!(out, "None\n}}")?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(
+            out,
+            r"impl<'a> TryFrom<&::paramdef_codegen::param::Row<'a>> for &'a {struct_name} {{
+type Error = ::std::io::Error;
+fn try_from(row: &::paramdef_codegen::param::Row<'a>) -> ::std::io::Result<Self> {{
+::paramdef_codegen::param::ParamRow::from_row(row)
+}}
+}}"
+        )
+    }
+}
+
+/// Types used to generate a param struct field.
+struct FieldTypes<'a> {
+    int_type: DefBaseRustType,
+    /// Big endian wrapper type of the field, if it has one.
+    be_type: Option<String>,
+    field_enum: Option<&'a ParamMetaEnum>,
+    ref_id: Option<String>,
+    is_bool: bool,
+    /// Type of the field's value as exposed by the struct, or of its elements for arrays which
+    /// are not strings.
+    rust_type: String,
+}
+
+fn is_string(f: &DefField) -> bool {
+    matches!(
+        f.field_def.base_type,
+        DefBaseType::Fixstr | DefBaseType::FixstrW
+    )
+}
+
+/// Returns the serialized name of a field, the expression of its serialized value and the
+/// statement setting it from the next value of a serde `MapAccess`.
+fn gen_serde_field(f: &DefField, field_name: &str, is_bool: bool) -> (String, String, String) {
+    let name = field_name.trim_start_matches("r#").to_owned();
+    match f.field_def.modifier {
+        DefTypeModifier::Array(_) if !is_string(f) => (
+            name,
+            format!("&self.{}[..]", field_name),
+            format!(
+                "self.{} = ::paramdef_codegen::row_serde::to_array(map.next_value()?)?",
+                field_name
+            ),
+        ),
+        DefTypeModifier::Bitfield(_) => (
+            name,
+            format!("&self.{}_get()", field_name),
+            match is_bool {
+                true => format!("self.{}_set(map.next_value()?)", field_name),
+                false => format!(
+                    "self.{}_set(map.next_value()?).map_err(<A::Error as ::paramdef_codegen::serde::de::Error>::custom)?",
+                    field_name
+                ),
+            },
+        ),
+        _ => (
+            name,
+            format!("&self.{}", field_name),
+            format!("self.{} = map.next_value()?", field_name),
+        ),
+    }
+}
+
+/// Whether a field is stored in one of the big endian wrapper types, which enums cannot be.
+fn is_be_wrapped(def: &Paramdef, field: &DefField, config: &CodegenParams) -> bool {
+    (config.big_endian || def.big_endian)
+        && !field.field_def.modifier.is_bitfield()
+        && field.field_def.base_type.rust_type().size_bytes() > 1
+}
+
+/// Returns a `FieldInfo` expression describing a field for the reflection table.
+fn gen_field_info(f: &DefField, field_name: &str, field_enum: Option<&ParamMetaEnum>) -> String {
+    let (bit_width, array_len) = match f.field_def.modifier {
+        DefTypeModifier::None => (None, None),
+        DefTypeModifier::Array(len) => (None, Some(len)),
+        DefTypeModifier::Bitfield(width) => (Some(width), None),
+    };
+    format!(
+        "::paramdef_codegen::reflect::FieldInfo {{
+name: {:?},
+display_name: {:?},
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::{:?},
+bit_offset: {},
+bit_width: {:?},
+array_len: {:?},
+enum_name: {:?},
+minimum: {:?},
+maximum: {:?},
+increment: {:?},
+}}",
+        field_name.trim_start_matches("r#"),
+        f.display_name,
+        f.field_def.base_type,
+        f.bit_offset.unwrap(),
+        bit_width,
+        array_len,
+        field_enum.map(|e| &e.name),
+        f.minimum,
+        f.maximum,
+        f.increment
+    )
+}
+
+/// Returns true if an enum must be generated as a newtype, either because it was requested or
+/// because Rust enums cannot have a float representation, no variants or several variants with the
+/// same value.
+fn is_newtype_enum(e: &ParamMetaEnum, config: &CodegenParams) -> bool {
+    let mut values = HashSet::new();
+    config.newtype_enums
+        || e.base_type.rust_type() == DefBaseRustType::F32
+        || e.options.is_empty()
+        || !e.options.iter().all(|o| values.insert(o.value))
+}
+
+/// Converts enum option names such as `Warp to grace` to unique CamelCase identifiers
+/// (`WarpToGrace`). Non-ASCII characters are dropped, names starting with a digit are prefixed
+/// with `_`, and names which are empty once sanitized are replaced by the option value.
+fn variant_idents(options: &[ParamEnumOption]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    options
+        .iter()
+        .map(|opt| {
+            let mut base: String = opt
+                .name
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .flat_map(|word| {
+                    let mut chars = word.chars();
+                    let first = chars.next().map(|c| c.to_ascii_uppercase());
+                    first.into_iter().chain(chars)
+                })
+                .collect();
+            if base.is_empty() {
+                base = match opt.value {
+                    v if v < 0 => format!("ValueNeg{}", v.unsigned_abs()),
+                    v => format!("Value{}", v),
+                };
+            }
+            if base.starts_with(|c: char| c.is_ascii_digit()) {
+                base.insert(0, '_');
+            }
+
+            let mut ident = base.clone();
+            let mut n = 1;
+            while !seen.insert(ident.clone()) {
+                n += 1;
+                ident = format!("{}_{}", base, n);
+            }
+            if ident == "Self" {
+                ident.push('_');
+            } else if is_keyword(&ident) {
+                ident.insert_str(0, "r#");
+            }
+            ident
+        })
+        .collect()
+}
+
+/// Returns the expression of a paramdef default value for a field of the given type.
+fn default_expr(
+    value: f64,
+    int_type: DefBaseRustType,
+    be_type: Option<&str>,
+    field_enum: Option<&ParamMetaEnum>,
+    ref_id: Option<String>,
+    config: &CodegenParams,
+) -> String {
+    let int = default_int(value, int_type);
+    let literal = match int_type {
+        DefBaseRustType::F32 => format!("{:?}", value as f32),
+        _ => int.to_string(),
+    };
+    let literal = match be_type {
+        Some(be_type) => format!("{}::new({})", be_type, literal),
+        None => literal,
+    };
+    match (field_enum, ref_id) {
+        (Some(e), _) if is_newtype_enum(e, config) => format!("{}({})", e.name, literal),
+        (Some(e), _) => {
+            // Fields whose default is not an enum value are not given the enum type
+            let index = e.options.iter().position(|o| o.value == int).unwrap();
+            format!("{}::{}", e.name, variant_idents(&e.options)[index])
+        }
+        (None, Some(id_type)) => format!("{}({})", id_type, literal),
+        (None, None) => literal,
+    }
+}
+
+/// Converts a paramdef default value to the integer type of a field, wrapping out of range values
+/// such as -1 for unsigned fields.
+fn default_int(value: f64, int_type: DefBaseRustType) -> i64 {
+    let int = value as i64;
+    match int_type {
+        DefBaseRustType::U8 => int as u8 as i64,
+        DefBaseRustType::I8 => int as i8 as i64,
+        DefBaseRustType::U16 => int as u16 as i64,
+        DefBaseRustType::I16 => int as i16 as i64,
+        DefBaseRustType::U32 => int as u32 as i64,
+        DefBaseRustType::I32 => int as i32 as i64,
+        DefBaseRustType::F32 => int,
+    }
+}
+
+/// Returns the value of an enum option as a literal of the enum's type.
+fn option_literal(opt: &ParamEnumOption, int_type: DefBaseRustType) -> String {
+    match int_type {
+        DefBaseRustType::F32 => format!("{:?}", opt.value as f32),
+        _ => opt.value.to_string(),
+    }
+}
+
+/// Returns an expression checking that `value` is one of the values of an enum.
+fn enum_check(options: &[ParamEnumOption], value: &str) -> String {
+    if options.is_empty() {
+        return "false".to_owned();
+    }
+    let values: Vec<_> = options.iter().map(|o| o.value.to_string()).collect();
+    format!("matches!({}, {})", value, values.join(" | "))
+}
+
+/// Converts a row name such as `Throwing Dagger +3` to a constant name (`THROWING_DAGGER_3`).
+/// Non-ASCII characters and apostrophes are dropped, and a leading digit is prefixed with `_`.
+/// Returns `None` if nothing is left of the name.
+fn const_ident(name: &str) -> Option<String> {
+    let mut ident = String::new();
+    for word in name
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .map(|w| w.chars().filter(char::is_ascii_alphanumeric))
+    {
+        let word: String = word.map(|c| c.to_ascii_uppercase()).collect();
+        if !word.is_empty() {
+            if !ident.is_empty() {
+                ident.push('_');
+            }
+            ident.push_str(&word);
+        }
+    }
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    (!ident.is_empty()).then_some(ident)
+}
+
+/// Returns unique constant names for rows sorted by ID. Rows whose name has no ASCII
+/// alphanumerics are named `ROW_{id}`, and rows sharing a name get their ID appended to it.
+/// Suffixed names which are still taken, such as `A_1` for the rows `A` (1), `A` (2) and `A 1`
+/// (3), get the ID appended again (`A_1_1`).
+fn row_const_idents(rows: &[(u32, &str)]) -> Vec<String> {
+    let bases: Vec<_> = rows
+        .iter()
+        .map(|&(id, name)| const_ident(name).unwrap_or_else(|| format!("ROW_{}", id)))
+        .collect();
+    let mut base_counts = HashMap::new();
+    for base in &bases {
+        *base_counts.entry(base.as_str()).or_insert(0) += 1;
+    }
+
+    // Names which are already unique are reserved first, so that they never get a suffix
+    let mut seen: HashSet<_> = bases
+        .iter()
+        .filter(|b| base_counts[b.as_str()] == 1)
+        .cloned()
+        .collect();
+    rows.iter()
+        .zip(&bases)
+        .map(|(&(id, _), base)| {
+            if base_counts[base.as_str()] == 1 {
+                return base.clone();
+            }
+            let mut ident = format!("{}_{}", base, id);
+            while !seen.insert(ident.clone()) {
+                ident = format!("{}_{}", ident, id);
+            }
+            ident
+        })
+        .collect()
+}
+
+/// Converts a param name such as `AIAttackParam` or `Gconfig_AAQuality` to a snake case module
+/// name (`ai_attack_param`, `gconfig_aa_quality`).
+fn module_name(param_name: &str) -> String {
+    let chars: Vec<_> = param_name.chars().collect();
+    let mut out = String::with_capacity(param_name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i != 0 && !out.ends_with('_') {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// Returns the Rust identifiers of the fields of `def`. A few paramdefs declare the same field name
+/// more than once, in which case the later occurences are suffixed with their occurence number.
+fn field_idents(def: &Paramdef) -> Vec<String> {
+    let mut seen = HashSet::new();
+    def.fields
+        .iter()
+        .map(|f| {
+            let mut ident = f.field_def.name.clone();
+            let mut n = 1;
+            while !seen.insert(ident.clone()) {
+                n += 1;
+                ident = format!("{}_{}", f.field_def.name, n);
+            }
+            if matches!(ident.as_str(), "self" | "Self" | "super" | "crate") {
+                ident.push('_');
+            } else if is_keyword(&ident) {
+                ident.insert_str(0, "r#");
+            }
+            ident
+        })
+        .collect()
+}
+
+/// Returns true if `ident` is a (strict or reserved) Rust keyword and cannot be used as-is.
+fn is_keyword(ident: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do",
+        "final", "gen", "macro", "override", "priv", "try", "typeof", "unsized", "virtual",
+        "yield",
+    ];
+    KEYWORDS.contains(&ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(names: &[&str]) -> Vec<ParamEnumOption> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| ParamEnumOption {
+                value: i as i64 - 1,
+                name: name.to_owned(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn variant_idents_are_valid_and_unique() {
+        let idents = variant_idents(&options(&[
+            "",
+            "warp to grace",
+            "Warp To Grace!",
+            "0",
+            "1409F7282 HKS",
+            "Self",
+            "Trait",
+            "デフォルト",
+        ]));
+        assert_eq!(
+            idents,
+            [
+                "ValueNeg1",
+                "WarpToGrace",
+                "WarpToGrace_2",
+                "_0",
+                "_1409F7282HKS",
+                "Self_",
+                "Trait",
+                "Value6"
+            ]
+        );
+    }
+
+    #[test]
+    fn row_const_idents_are_unique() {
+        let rows = [
+            (1, "A"),
+            (2, "A"),
+            (3, "A 1"),
+            (4, "???"),
+            (5, "Row 6"),
+            (6, "デフォルト"),
+        ];
+        assert_eq!(
+            row_const_idents(&rows),
+            ["A_1_1", "A_2", "A_1", "ROW_4", "ROW_6_5", "ROW_6_6"]
+        );
+    }
+
+    #[test]
+    fn newtype_enums() {
+        let mut e = ParamMetaEnum {
+            name: "TEST".to_owned(),
+            base_type: DefBaseType::U8,
+            options: options(&["A", "B"]),
+        };
+        let config = CodegenParams::default();
+        assert!(!is_newtype_enum(&e, &config));
+        let newtypes = CodegenParams {
+            newtype_enums: true,
+            ..Default::default()
+        };
+        assert!(is_newtype_enum(&e, &newtypes));
+        e.options[1].value = -1;
+        assert!(is_newtype_enum(&e, &config));
+        e.options.clear();
+        assert!(is_newtype_enum(&e, &config));
+        e.base_type = DefBaseType::F32;
+        e.options = options(&["A"]);
+        assert!(is_newtype_enum(&e, &config));
     }
 }
@@ -1,21 +1,39 @@
-use byteorder::{ReadBytesExt, BE};
-use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom::*};
+use byteorder::{ReadBytesExt, WriteBytesExt, BE};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom::*, Write};
+use std::sync::{Arc, RwLock};
 
-use flate2::{Decompress, FlushDecompress, Status};
+use flate2::{read::DeflateDecoder, write::ZlibEncoder, Compression, Decompress, FlushDecompress};
 
-use crate::binary_utils::{assert_read, ReadExt, SeekExt};
+use crate::binary_utils::{assert_read, ReadExt, SeekExt, WriteExt};
 
 #[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Kind {
     Unknown,
     DCP_DFLT,
     DCP_EDGE,
     DCX_EDGE,
     DCX_DFLT(u32, u32, u8, u8),
-    DCX_KRAK, // Unsupported (requires Oodle)
+    DCX_KRAK(u8), // Requires a registered Oodle codec. Holds the compression level
+    DCX_ZSTD(u8), // Holds the compression level
     Zlib,
 }
 
+/// Compression algorithm of a DCX format not implemented by this crate, such as Oodle Kraken.
+/// Implementations are provided at runtime through [`DCX::register_codec`].
+pub trait DcxCodec: Send + Sync {
+    fn decompress(&self, compressed: &[u8], uncompressed_size: usize) -> Result<Vec<u8>>;
+
+    /// Compresses `data` with the compression level stored in the DCX header.
+    fn compress(&self, data: &[u8], level: u8) -> Result<Vec<u8>>;
+}
+
+lazy_static! {
+    static ref CODECS: RwLock<HashMap<[u8; 4], Arc<dyn DcxCodec>>> = Default::default();
+}
+
 pub struct DCX;
 impl DCX {
     pub fn is(bytes: &[u8]) -> bool {
@@ -32,10 +50,17 @@ impl DCX {
     }
 
     pub fn decompress(r: &mut (impl Read + Seek)) -> Result<Vec<u8>> {
+        Ok(Self::decompress_with_kind(r)?.0)
+    }
+
+    /// Decompresses the DCX, also returning its [`Kind`] so that the data can be recompressed
+    /// in the same variant with [`DCX::compress`].
+    pub fn decompress_with_kind(r: &mut (impl Read + Seek)) -> Result<(Vec<u8>, Kind)> {
         let start = r.stream_position()?;
 
         let mut kind = Kind::Unknown;
-        let (mut compressed_size, mut uncompressed_size) = (0, None);
+        let compressed_size;
+        let mut uncompressed_size = None;
         let magic = r.read_slice()?;
         if &magic == b"DCP\0" {
             kind = match &r.read_slice()? {
@@ -43,13 +68,14 @@ impl DCX {
                 b"EDGE" => Kind::DCP_EDGE,
                 _ => Kind::Unknown,
             };
-            uncompressed_size = Some(r.at(0x1C)?.read_u32::<BE>()?);
+            uncompressed_size = Some(r.at(start + 0x24)?.read_u32::<BE>()?);
             compressed_size = r.read_u32::<BE>()?;
         } else if &magic == b"DCX\0" {
             r.seek(Start(start + 0x28))?;
             kind = match &r.read_slice()? {
                 b"EDGE" => Kind::DCX_EDGE,
-                b"KRAK" => Kind::DCX_KRAK,
+                b"KRAK" => Kind::DCX_KRAK(r.at(start + 0x30)?.read_u8()?),
+                b"ZSTD" => Kind::DCX_ZSTD(r.at(start + 0x30)?.read_u8()?),
                 b"DFLT" => {
                     let unk04 = r.at(start + 4)?.read_u32::<BE>()?;
                     let unk10 = r.at(start + 0x10)?.read_u32::<BE>()?;
@@ -60,7 +86,7 @@ impl DCX {
                 }
                 _ => Kind::Unknown,
             };
-            uncompressed_size = Some(r.at(0x1C)?.read_u32::<BE>()?);
+            uncompressed_size = Some(r.at(start + 0x1C)?.read_u32::<BE>()?);
             compressed_size = r.read_u32::<BE>()?;
         } else {
             let b0 = r.at(start)?.read_u8()?;
@@ -68,24 +94,25 @@ impl DCX {
             if b0 == 0x78 && (b1 == 0x01 || b1 == 0x5E || b1 == 0x9C || b1 == 0xDA) {
                 kind = Kind::Zlib;
             }
-            compressed_size = r.my_stream_len()? as u32;
+            compressed_size = (r.seek(End(0))? - start) as u32;
         }
 
         r.seek(Start(start))?;
-        match kind {
+        let data = match kind {
             Kind::Zlib => Self::read_zlib(r, compressed_size, uncompressed_size),
             Kind::DCP_DFLT => {
-                Self::read_zlib(r.at(start + 0x24)?, compressed_size, uncompressed_size)
+                Self::read_zlib(r.at(start + 0x2C)?, compressed_size, uncompressed_size)
             }
             Kind::DCX_DFLT(_, _, _, _) => {
                 Self::read_zlib(r.at(start + 0x4C)?, compressed_size, uncompressed_size)
             }
             Kind::DCP_EDGE => {
-                let data_start = r.seek(Start(start + 0x28))?;
-                r.seek(Current(compressed_size as i64))?;
+                assert_read(r.at(start + 0x2C)?.read_u32::<BE>()? == 0, "0")?;
+                let data_start = start + 0x30;
+                r.seek(Start(data_start + compressed_size as u64))?;
 
                 assert_read(&r.read_slice()? == b"DCA\0", "DCA0 magic invalid")?;
-                let dca_size = r.read_u32::<BE>()?;
+                let _dca_size = r.read_u32::<BE>()?;
 
                 assert_read(&r.read_slice()? == b"EgdT", "EgdT magic invalid")?;
                 assert_read(r.read_u32::<BE>()? == 0x00010000, "0x00010000")?;
@@ -104,25 +131,219 @@ impl DCX {
                     ));
                 }
 
-                let mut decompressed = vec![0u8; uncompressed_size.unwrap() as usize];
-                let mut pos = 0;
-                for i in 0..chunk_count {
-                    assert_read(r.read_u32::<BE>()? == 0, "0")?;
-                    let offset = r.read_u32::<BE>()?;
-                    let size = r.read_u32::<BE>()?;
-                    let compressed = r.read_u32::<BE>()? != 0;
+                Self::read_edge_chunks(r, data_start, chunk_count, uncompressed_size.unwrap())
+            }
+            Kind::DCX_EDGE => {
+                let unk14 = r.at(start + 0x14)?.read_u32::<BE>()?;
+                let dca_start = start + 0x44;
 
-                    todo!();
+                assert_read(
+                    &r.at(dca_start)?.read_slice()? == b"DCA\0",
+                    "DCA0 magic invalid",
+                )?;
+                let dca_size = r.read_u32::<BE>()?;
+
+                assert_read(&r.read_slice()? == b"EgdT", "EgdT magic invalid")?;
+                assert_read(r.read_u32::<BE>()? == 0x00010100, "0x00010100")?;
+                assert_read(r.read_u32::<BE>()? == 0x24, "0x24")?;
+                assert_read(r.read_u32::<BE>()? == 0x10, "0x10")?;
+                assert_read(r.read_u32::<BE>()? == 0x10000, "0x10000")?;
+
+                let _last_chunk_uncompressed_size = r.read_u32::<BE>()?;
+                let egdt_size = r.read_u32::<BE>()?;
+                let chunk_count = r.read_u32::<BE>()?;
+                assert_read(r.read_u32::<BE>()? == 0x100000, "0x100000")?;
+
+                if unk14 != 0x50 + chunk_count * 0x10 || egdt_size != 0x24 + chunk_count * 0x10 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Unexpected EgdT size in EDGE DCX.",
+                    ));
                 }
 
-                Ok(decompressed)
+                Self::read_edge_chunks(
+                    r,
+                    dca_start + dca_size as u64,
+                    chunk_count,
+                    uncompressed_size.unwrap(),
+                )
             }
-            Kind::DCX_EDGE => {
-                todo!();
+            Kind::DCX_KRAK(_) => {
+                let codec = Self::codec(b"KRAK")?;
+                let mut compressed = vec![0u8; compressed_size as usize];
+                r.at(start + 0x4C)?.read_exact(&mut compressed)?;
+                codec.decompress(&compressed, uncompressed_size.unwrap() as usize)
+            }
+            Kind::DCX_ZSTD(_) => {
+                let mut compressed = vec![0u8; compressed_size as usize];
+                r.at(start + 0x4C)?.read_exact(&mut compressed)?;
+                zstd::bulk::decompress(&compressed, uncompressed_size.unwrap() as usize)
             }
-            Kind::DCX_KRAK => Err(Error::new(ErrorKind::InvalidData, "KRAK not supported")),
             Kind::Unknown => Err(Error::new(ErrorKind::InvalidData, "Unknown DCX type")),
+        }?;
+        Ok((data, kind))
+    }
+
+    /// Compresses `data` in the given DCX variant. Only the zlib based variants are supported.
+    pub fn compress(data: &[u8], kind: Kind) -> Result<Vec<u8>> {
+        let uncompressed_size = u32::try_from(data.len()).or(Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Data too large for DCX",
+        )))?;
+        let mut w = Vec::new();
+
+        match kind {
+            Kind::Zlib => Self::write_zlib(&mut w, data)?,
+            Kind::DCP_DFLT => {
+                w.write_all(b"DCP\0DFLT")?;
+                w.write_u32::<BE>(0x20)?;
+                w.write_u32::<BE>(0x9000000)?;
+                w.write_zeros(12)?;
+                w.write_u32::<BE>(0x00010100)?;
+
+                let compressed = Self::zlib(data)?;
+                w.write_all(b"DCS\0")?;
+                w.write_u32::<BE>(uncompressed_size)?;
+                w.write_u32::<BE>(compressed.len() as u32)?;
+                w.write_all(&compressed)?;
+
+                w.write_all(b"DCA\0")?;
+                w.write_u32::<BE>(8)?;
+            }
+            Kind::DCX_DFLT(unk04, unk10, unk30, unk38) => {
+                let compressed = Self::zlib(data)?;
+                let header = (unk04, unk10, unk30, unk38);
+                Self::write_dcx(&mut w, b"DFLT", header, uncompressed_size, &compressed)?;
+            }
+            Kind::DCX_KRAK(level) => {
+                let compressed = Self::codec(b"KRAK")?.compress(data, level)?;
+                let header = (0x11000, 0x44, level, 0);
+                Self::write_dcx(&mut w, b"KRAK", header, uncompressed_size, &compressed)?;
+            }
+            Kind::DCX_ZSTD(level) => {
+                let compressed = zstd::bulk::compress(data, level as i32)?;
+                let header = (0x11000, 0x44, level, 0);
+                Self::write_dcx(&mut w, b"ZSTD", header, uncompressed_size, &compressed)?;
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!("Compressing {:?} is not supported", kind),
+                ))
+            }
+        }
+        Ok(w)
+    }
+
+    /// Writes a DCX container with the given format and `(unk04, unk10, unk30, unk38)` fields.
+    fn write_dcx(
+        w: &mut impl Write,
+        format: &[u8; 4],
+        (unk04, unk10, unk30, unk38): (u32, u32, u8, u8),
+        uncompressed_size: u32,
+        compressed: &[u8],
+    ) -> Result<()> {
+        w.write_all(b"DCX\0")?;
+        w.write_u32::<BE>(unk04)?;
+        w.write_u32::<BE>(0x18)?;
+        w.write_u32::<BE>(0x24)?;
+        w.write_u32::<BE>(unk10)?;
+        w.write_u32::<BE>(unk10 + 8)?;
+
+        w.write_all(b"DCS\0")?;
+        w.write_u32::<BE>(uncompressed_size)?;
+        w.write_u32::<BE>(compressed.len() as u32)?;
+
+        w.write_all(b"DCP\0")?;
+        w.write_all(format)?;
+        w.write_u32::<BE>(0x20)?;
+        w.write_u8(unk30)?;
+        w.write_zeros(7)?;
+        w.write_u8(unk38)?;
+        w.write_zeros(7)?;
+        w.write_u32::<BE>(0x00010100)?;
+
+        w.write_all(b"DCA\0")?;
+        w.write_u32::<BE>(8)?;
+        w.write_all(compressed)?;
+
+        let len = 0x4C + compressed.len();
+        w.write_zeros(len.next_multiple_of(0x10) - len)
+    }
+
+    /// Registers the codec used for the DCX format with the given magic (e.g. `b"KRAK"`),
+    /// replacing any previously registered one.
+    pub fn register_codec(format: [u8; 4], codec: impl DcxCodec + 'static) {
+        CODECS.write().unwrap().insert(format, Arc::new(codec));
+    }
+
+    fn codec(format: &[u8; 4]) -> Result<Arc<dyn DcxCodec>> {
+        CODECS.read().unwrap().get(format).cloned().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "DCX format {} requires an external codec, but none is registered (see DCX::register_codec)",
+                    String::from_utf8_lossy(format)
+                ),
+            )
+        })
+    }
+
+    fn zlib(data: &[u8]) -> Result<Vec<u8>> {
+        let mut compressed = Vec::new();
+        Self::write_zlib(&mut compressed, data)?;
+        Ok(compressed)
+    }
+
+    fn write_zlib(w: &mut impl Write, data: &[u8]) -> Result<()> {
+        let mut encoder = ZlibEncoder::new(w, Compression::best());
+        encoder.write_all(data)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Reads the chunk table of an EDGE DCX, which follows the EgdT header. Chunks are either raw
+    /// deflate streams or stored as is.
+    fn read_edge_chunks(
+        r: &mut (impl Read + Seek),
+        data_start: u64,
+        chunk_count: u32,
+        uncompressed_size: u32,
+    ) -> Result<Vec<u8>> {
+        let mut decompressed = Vec::with_capacity(uncompressed_size as usize);
+        for _ in 0..chunk_count {
+            assert_read(r.read_u32::<BE>()? == 0, "0")?;
+            let offset = r.read_u32::<BE>()?;
+            let size = r.read_u32::<BE>()?;
+            let compressed = match r.read_u32::<BE>()? {
+                0 => false,
+                1 => true,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Invalid EDGE chunk flag",
+                    ))
+                }
+            };
+
+            let chunk = r.do_at(Start(data_start + offset as u64), |r| {
+                let mut chunk = vec![0u8; size as usize];
+                r.read_exact(&mut chunk)?;
+                Ok(chunk)
+            })?;
+
+            if compressed {
+                DeflateDecoder::new(chunk.as_slice()).read_to_end(&mut decompressed)?;
+            } else {
+                decompressed.extend_from_slice(&chunk);
+            }
         }
+
+        assert_read(
+            decompressed.len() == uncompressed_size as usize,
+            "EDGE DCX uncompressed size mismatch",
+        )?;
+        Ok(decompressed)
     }
 
     fn read_zlib(
@@ -131,11 +352,7 @@ impl DCX {
         uncompressed_size: Option<u32>,
     ) -> Result<Vec<u8>> {
         assert_read(
-            r.read_u8()? == 0x78
-                && match r.read_u8()? {
-                    0x01 | 0x5E | 0x9C | 0xDA => true,
-                    _ => false,
-                },
+            r.read_u8()? == 0x78 && matches!(r.read_u8()?, 0x01 | 0x5E | 0x9C | 0xDA),
             "Not a ZLIB block",
         )?;
 
@@ -149,7 +366,187 @@ impl DCX {
             FlushDecompress::Finish,
         );
 
-        res.or_else(|err| Err(Error::new(ErrorKind::InvalidData, err)))?;
+        res.map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
         Ok(out_buf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn assert_round_trip(data: &[u8], kind: Kind) {
+        let compressed = DCX::compress(data, kind).unwrap();
+        assert!(DCX::is(&compressed));
+
+        let (decompressed, read_kind) =
+            DCX::decompress_with_kind(&mut Cursor::new(compressed.as_slice())).unwrap();
+        assert_eq!(read_kind, kind);
+        assert!(decompressed == data, "{:?} round trip differs", kind);
+    }
+
+    #[test]
+    fn dflt_round_trip() {
+        let data: Vec<u8> = (0..100000u64).map(|i| (i * i % 251) as u8).collect();
+        assert_round_trip(&data, Kind::Zlib);
+        assert_round_trip(&data, Kind::DCP_DFLT);
+        assert_round_trip(&data, Kind::DCX_DFLT(0x10000, 0x24, 9, 0));
+        assert_round_trip(&data, Kind::DCX_DFLT(0x11000, 0x44, 9, 0x0F));
+    }
+
+    #[test]
+    fn zstd_round_trip() {
+        let data: Vec<u8> = (0..100000u64).map(|i| (i * i % 251) as u8).collect();
+        assert_round_trip(&data, Kind::DCX_ZSTD(0x15));
+
+        let compressed = DCX::compress(&data, Kind::DCX_ZSTD(0x15)).unwrap();
+        assert_eq!(&compressed[0x28..0x2C], b"ZSTD");
+        assert_eq!(compressed.len() % 0x10, 0);
+    }
+
+    /// Splits `data` into 64 KiB EDGE chunks, deflating every other one. Returns the chunk table
+    /// (offset, size, compressed) and the chunk data.
+    fn edge_chunks(data: &[u8]) -> (Vec<(u32, u32, bool)>, Vec<u8>) {
+        use flate2::write::DeflateEncoder;
+
+        let mut table = Vec::new();
+        let mut blob = Vec::new();
+        for (i, chunk) in data.chunks(0x10000).enumerate() {
+            let compressed = i % 2 == 0;
+            let bytes = match compressed {
+                true => {
+                    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+                    encoder.write_all(chunk).unwrap();
+                    encoder.finish().unwrap()
+                }
+                false => chunk.to_vec(),
+            };
+            table.push((blob.len() as u32, bytes.len() as u32, compressed));
+            blob.extend(bytes);
+        }
+        (table, blob)
+    }
+
+    fn write_edge_table(w: &mut Vec<u8>, table: &[(u32, u32, bool)]) {
+        w.write_u32::<BE>(table.len() as u32).unwrap();
+        w.write_u32::<BE>(0x100000).unwrap();
+        for &(offset, size, compressed) in table {
+            w.write_u32::<BE>(0).unwrap();
+            w.write_u32::<BE>(offset).unwrap();
+            w.write_u32::<BE>(size).unwrap();
+            w.write_u32::<BE>(compressed as u32).unwrap();
+        }
+    }
+
+    fn edge_test_data() -> Vec<u8> {
+        (0..0x2_1234u64).map(|i| (i * i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn dcp_edge_decompress() {
+        let data = edge_test_data();
+        let (table, blob) = edge_chunks(&data);
+        let egdt_size = 0x20 + 0x10 * table.len() as u32;
+
+        let mut w = Vec::new();
+        w.write_all(b"DCP\0EDGE").unwrap();
+        for v in [0x20, 0x9000000, 0x10000, 0, 0, 0x00100100] {
+            w.write_u32::<BE>(v).unwrap();
+        }
+        w.write_all(b"DCS\0").unwrap();
+        w.write_u32::<BE>(data.len() as u32).unwrap();
+        w.write_u32::<BE>(blob.len() as u32).unwrap();
+        w.write_u32::<BE>(0).unwrap();
+        w.write_all(&blob).unwrap();
+
+        w.write_all(b"DCA\0").unwrap();
+        w.write_u32::<BE>(8 + egdt_size).unwrap();
+        w.write_all(b"EgdT").unwrap();
+        for v in [0x00010000, 0x20, 0x10, 0x10000, egdt_size] {
+            w.write_u32::<BE>(v).unwrap();
+        }
+        write_edge_table(&mut w, &table);
+
+        let (decompressed, kind) =
+            DCX::decompress_with_kind(&mut Cursor::new(w.as_slice())).unwrap();
+        assert_eq!(kind, Kind::DCP_EDGE);
+        assert!(decompressed == data, "DCP_EDGE decompression differs");
+    }
+
+    #[test]
+    fn dcx_edge_decompress() {
+        let data = edge_test_data();
+        let (table, blob) = edge_chunks(&data);
+        let egdt_size = 0x24 + 0x10 * table.len() as u32;
+
+        let mut w = Vec::new();
+        w.write_all(b"DCX\0").unwrap();
+        for v in [0x10000, 0x18, 0x24, 0x24, 0x50 + 0x10 * table.len() as u32] {
+            w.write_u32::<BE>(v).unwrap();
+        }
+        w.write_all(b"DCS\0").unwrap();
+        w.write_u32::<BE>(data.len() as u32).unwrap();
+        w.write_u32::<BE>(blob.len() as u32).unwrap();
+        w.write_all(b"DCP\0EDGE").unwrap();
+        for v in [0x20, 0x9000000, 0x10000, 0, 0, 0x00100100] {
+            w.write_u32::<BE>(v).unwrap();
+        }
+
+        w.write_all(b"DCA\0").unwrap();
+        w.write_u32::<BE>(8 + egdt_size).unwrap();
+        w.write_all(b"EgdT").unwrap();
+        let last_chunk_size = data.len() as u32 % 0x10000;
+        for v in [0x00010100, 0x24, 0x10, 0x10000, last_chunk_size, egdt_size] {
+            w.write_u32::<BE>(v).unwrap();
+        }
+        write_edge_table(&mut w, &table);
+        w.write_all(&blob).unwrap();
+
+        let (decompressed, kind) =
+            DCX::decompress_with_kind(&mut Cursor::new(w.as_slice())).unwrap();
+        assert_eq!(kind, Kind::DCX_EDGE);
+        assert!(decompressed == data, "DCX_EDGE decompression differs");
+    }
+
+    /// Stand-in for an external codec which stores the data as is.
+    struct StoreCodec;
+    impl DcxCodec for StoreCodec {
+        fn decompress(&self, compressed: &[u8], uncompressed_size: usize) -> Result<Vec<u8>> {
+            assert_eq!(compressed.len(), uncompressed_size);
+            Ok(compressed.to_vec())
+        }
+
+        fn compress(&self, data: &[u8], _level: u8) -> Result<Vec<u8>> {
+            Ok(data.to_vec())
+        }
+    }
+
+    #[test]
+    fn krak_requires_registered_codec() {
+        let data = edge_test_data();
+        let err = DCX::compress(&data, Kind::DCX_KRAK(6)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+        assert!(err.to_string().contains("KRAK"));
+
+        DCX::register_codec(*b"KRAK", StoreCodec);
+        assert_round_trip(&data, Kind::DCX_KRAK(6));
+    }
+
+    #[test]
+    fn dflt_header_is_identical() {
+        use crate::game::{Game, ER};
+
+        let encrypted = std::fs::read("regulations/er").unwrap();
+        let original = ER::decrypt_regulation_data(&encrypted).unwrap().data;
+        let (data, kind) =
+            DCX::decompress_with_kind(&mut Cursor::new(original.as_slice())).unwrap();
+        assert_eq!(kind, Kind::DCX_DFLT(0x11000, 0x44, 9, 0x0F));
+
+        // Everything but the compressed size must match
+        let compressed = DCX::compress(&data, kind).unwrap();
+        assert_eq!(compressed[..0x20], original[..0x20]);
+        assert_eq!(compressed[0x24..0x4C], original[0x24..0x4C]);
+        assert_eq!(compressed.len() % 0x10, 0);
+    }
+}
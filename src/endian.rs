@@ -0,0 +1,119 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! be_type {
+    ($name:ident, $t:ty, $raw:ty, |$v:ident| $to_raw:expr, |$r:ident| $from_raw:expr) => {
+        #[doc = concat!("A big endian `", stringify!($t), "` field of a big endian param.")]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default)]
+        pub struct $name($raw);
+
+        impl $name {
+            pub const fn new(value: $t) -> Self {
+                let $v = value;
+                Self(($to_raw).to_be())
+            }
+
+            pub const fn get(self) -> $t {
+                let $r = <$raw>::from_be(self.0);
+                $from_raw
+            }
+
+            pub fn set(&mut self, value: $t) {
+                *self = Self::new(value);
+            }
+        }
+
+        impl From<$t> for $name {
+            fn from(value: $t) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl PartialEq<$t> for $name {
+            fn eq(&self, other: &$t) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$t>::deserialize(deserializer).map(Self::new)
+            }
+        }
+    };
+    ($name:ident, $t:ty) => {
+        be_type!($name, $t, $t, |v| v, |v| v);
+
+        impl Eq for $name {}
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+    };
+}
+
+be_type!(U16Be, u16);
+be_type!(I16Be, i16);
+be_type!(U32Be, u32);
+be_type!(I32Be, i32);
+be_type!(F32Be, f32, u32, |v| v.to_bits(), |r| f32::from_bits(r));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_as_big_endian() {
+        let mut v = U32Be::new(0x12345678);
+        assert_eq!(
+            unsafe { std::mem::transmute::<U32Be, [u8; 4]>(v) },
+            [0x12, 0x34, 0x56, 0x78]
+        );
+        v.set(1);
+        assert_eq!(v, 1);
+        assert_eq!(i16::from(I16Be::new(-2)), -2);
+
+        let f = F32Be::new(1.5);
+        assert_eq!(
+            unsafe { std::mem::transmute::<F32Be, [u8; 4]>(f) },
+            1.5f32.to_be_bytes()
+        );
+        assert_eq!(f.get(), 1.5);
+        assert_eq!(format!("{:?}", f), "1.5");
+    }
+}
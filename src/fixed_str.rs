@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{Error, ErrorKind, Result};
+use std::result::Result as StdResult;
+
+use byteorder::NativeEndian;
+use encoding_rs::SHIFT_JIS;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::binary_utils::{shift_jis_cstring_bytes, wide_cstring_bytes};
+
+/// A `fixstr` param field: a Shift-JIS string stored in `N` bytes, null-terminated unless it
+/// fills the whole buffer.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedStr<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedStr<N> {
+    /// Decodes the string. Invalid characters are replaced by U+FFFD.
+    pub fn get(&self) -> Cow<'_, str> {
+        let len = self.0.iter().position(|&b| b == 0).unwrap_or(N);
+        SHIFT_JIS.decode_without_bom_handling(&self.0[..len]).0
+    }
+
+    /// Replaces the string. Fails if it cannot be encoded in Shift-JIS or is longer than `N`
+    /// bytes once encoded.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        let mut bytes = shift_jis_cstring_bytes(s)?;
+        bytes.pop();
+        self.0 = fill_buffer(&bytes, s)?;
+        Ok(())
+    }
+}
+
+/// A `fixstrW` param field: a UTF-16 string stored in `N` code units, null-terminated unless it
+/// fills the whole buffer.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedWStr<const N: usize>(pub [u16; N]);
+
+impl<const N: usize> FixedWStr<N> {
+    /// Decodes the string. Invalid characters are replaced by U+FFFD.
+    pub fn get(&self) -> Cow<'_, str> {
+        Cow::Owned(decode_wstr(&self.0))
+    }
+
+    /// Replaces the string. Fails if it is longer than `N` UTF-16 code units.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        self.0 = encode_wstr(s)?;
+        Ok(())
+    }
+}
+
+/// A `fixstrW` field of a big endian param, whose code units are stored big endian.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedWStrBe<const N: usize>(pub [u16; N]);
+
+impl<const N: usize> FixedWStrBe<N> {
+    /// Decodes the string. Invalid characters are replaced by U+FFFD.
+    pub fn get(&self) -> Cow<'_, str> {
+        Cow::Owned(decode_wstr(&self.0.map(u16::from_be)))
+    }
+
+    /// Replaces the string. Fails if it is longer than `N` UTF-16 code units.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        self.0 = encode_wstr::<N>(s)?.map(u16::to_be);
+        Ok(())
+    }
+}
+
+fn decode_wstr(units: &[u16]) -> String {
+    let len = units.iter().position(|&c| c == 0).unwrap_or(units.len());
+    String::from_utf16_lossy(&units[..len])
+}
+
+fn encode_wstr<const N: usize>(s: &str) -> Result<[u16; N]> {
+    let bytes = wide_cstring_bytes::<NativeEndian>(s);
+    let units: Vec<_> = bytes[..bytes.len() - 2]
+        .chunks(2)
+        .map(|c| u16::from_ne_bytes([c[0], c[1]]))
+        .collect();
+    fill_buffer(&units, s)
+}
+
+/// Copies `encoded` into a zero-filled buffer of `N` elements.
+fn fill_buffer<T: Copy + Default, const N: usize>(encoded: &[T], s: &str) -> Result<[T; N]> {
+    if encoded.len() > N {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("String {:?} does not fit in {} characters", s, N),
+        ));
+    }
+    let mut buf = [T::default(); N];
+    buf[..encoded.len()].copy_from_slice(encoded);
+    Ok(buf)
+}
+
+macro_rules! impl_traits {
+    ($t:ident, $zero:expr) => {
+        impl<const N: usize> Default for $t<N> {
+            fn default() -> Self {
+                Self([$zero; N])
+            }
+        }
+
+        impl<const N: usize> fmt::Debug for $t<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl<const N: usize> fmt::Display for $t<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.get())
+            }
+        }
+
+        impl<const N: usize> Serialize for $t<N> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+                serializer.serialize_str(&self.get())
+            }
+        }
+
+        impl<'de, const N: usize> Deserialize<'de> for $t<N> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+                let s = Cow::<str>::deserialize(deserializer)?;
+                let mut value = Self::default();
+                value.set(&s).map_err(de::Error::custom)?;
+                Ok(value)
+            }
+        }
+    };
+}
+impl_traits!(FixedStr, 0u8);
+impl_traits!(FixedWStr, 0u16);
+impl_traits!(FixedWStrBe, 0u16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_str_round_trip() {
+        let mut s = FixedStr::<8>::default();
+        assert_eq!(s.get(), "");
+        s.set("ボーン").unwrap();
+        assert_eq!(s.0[6..], [0, 0]);
+        assert_eq!(s.get(), "ボーン");
+        s.set("Bone_001").unwrap();
+        assert_eq!(s.get(), "Bone_001");
+        assert!(s.set("Bone_0001").is_err());
+        assert_eq!(s.get(), "Bone_001");
+    }
+
+    #[test]
+    fn fixed_wstr_round_trip() {
+        let mut s = FixedWStr::<4>::default();
+        s.set("Été").unwrap();
+        assert_eq!(s.get(), "Été");
+        assert_eq!(s.0, [0xC9, b't' as u16, 0xE9, 0]);
+        s.set("abcd").unwrap();
+        assert_eq!(s.get(), "abcd");
+        assert!(s.set("abcde").is_err());
+
+        s.0[0] = 0xD800;
+        assert_eq!(s.get(), "\u{FFFD}bcd");
+    }
+
+    #[test]
+    fn fixed_wstr_be_round_trip() {
+        let mut s = FixedWStrBe::<4>::default();
+        s.set("Été").unwrap();
+        assert_eq!(s.get(), "Été");
+        assert_eq!(s.0.map(u16::from_be), [0xC9, b't' as u16, 0xE9, 0]);
+    }
+}
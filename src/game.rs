@@ -1,60 +1,137 @@
-use aes::cipher::{BlockDecryptMut, KeyIvInit, StreamCipher};
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, StreamCipher};
 use std::io::Result;
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Error, ErrorKind, Read};
 
 use crate::bnd4::BND4;
 
+/// Decrypted contents of a regulation file.
+pub struct RegulationData {
+    /// Unencrypted header found before the encrypted data, which contains the IV. Empty if the
+    /// regulation was not encrypted, in which case a fresh IV will be used to encrypt it.
+    pub header: Vec<u8>,
+    /// Decrypted data. This is usually a DCX compressed BND4.
+    pub data: Vec<u8>,
+}
+
 pub trait Game {
     const NAME: &'static str;
-    fn decrypt_regulation(encrypted: &[u8]) -> Result<BND4>;
+
+    fn decrypt_regulation_data(encrypted: &[u8]) -> Result<RegulationData>;
+
+    /// Encrypts regulation data with the game's key, reusing the IV stored in
+    /// `regulation.header`, or generating a fresh one if the header is empty. DS3 and ER data is
+    /// zero-padded to the AES block size.
+    ///
+    /// The output of [`Game::decrypt_regulation_data`] is re-encrypted into the original file
+    /// only as long as its `header` is kept, so that the same IV is used, and its `data` still
+    /// ends with the original block padding, which decryption leaves in place.
+    fn encrypt_regulation_data(regulation: &RegulationData) -> Result<Vec<u8>>;
+
+    fn decrypt_regulation(encrypted: &[u8]) -> Result<BND4> {
+        let regulation = Self::decrypt_regulation_data(encrypted)?;
+        BND4::read(&mut Cursor::new(regulation.data))
+    }
+
+    /// Serializes and encrypts a regulation BND4 with a fresh IV. The archive is recompressed in
+    /// the DCX variant it was read in.
+    fn encrypt_regulation(regulation: &BND4) -> Result<Vec<u8>> {
+        Self::encrypt_regulation_data(&RegulationData {
+            header: Vec::new(),
+            data: regulation.to_bytes()?,
+        })
+    }
 }
 
-static DS2_REGULATION_KEY: &'static [u8; 16] = &[
+static DS2_REGULATION_KEY: &[u8; 16] = &[
     0x40, 0x17, 0x81, 0x30, 0xDF, 0x0A, 0x94, 0x54, 0x33, 0x09, 0xE1, 0x71, 0xEC, 0xBF, 0x25, 0x4C,
 ];
 
-static DS3_REGULATION_KEY: &'static [u8; 32] = b"ds3#jn/8_7(rsY9pg55GFN7VFL#+3n/)";
+static DS3_REGULATION_KEY: &[u8; 32] = b"ds3#jn/8_7(rsY9pg55GFN7VFL#+3n/)";
 
-static ER_REGULATION_KEY: &'static [u8; 32] = &[
+static ER_REGULATION_KEY: &[u8; 32] = &[
     0x99, 0xBF, 0xFC, 0x36, 0x6A, 0x6B, 0xC8, 0xC6, 0xF5, 0x82, 0x7D, 0x09, 0x36, 0x02, 0xD6, 0x76,
     0xC4, 0x28, 0x92, 0xA0, 0x1C, 0x20, 0x7F, 0xB0, 0x24, 0xD3, 0xAF, 0x4E, 0x49, 0x3F, 0xEF, 0x99,
 ];
 
+fn fresh_header(size: usize) -> Result<Vec<u8>> {
+    let mut header = vec![0u8; size];
+    getrandom::getrandom(&mut header).map_err(Error::other)?;
+    Ok(header)
+}
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+fn ds2_iv(header: &[u8]) -> [u8; 16] {
+    let mut iv = [0u8; 16];
+    iv[0] = 0x80;
+    iv[1..=11].copy_from_slice(&header[..11]);
+    iv[15] = 1;
+    iv
+}
+
 pub struct DS2;
 impl Game for DS2 {
     const NAME: &'static str = "DS2";
-    fn decrypt_regulation(encrypted: &[u8]) -> Result<BND4> {
-        let mut c = Cursor::new(encrypted);
+    fn decrypt_regulation_data(encrypted: &[u8]) -> Result<RegulationData> {
         if BND4::is(encrypted) {
-            return Ok(BND4::read(&mut c)?);
+            return Ok(RegulationData {
+                header: Vec::new(),
+                data: encrypted.to_vec(),
+            });
         }
 
-        let mut iv = [0u8; 16];
-        iv[0] = 0x80;
-        iv[15] = 1;
-        c.read_exact(iv.get_mut(1..=11).unwrap())?;
-        c.set_position(32);
+        let mut c = Cursor::new(encrypted);
+        let mut header = vec![0u8; 32];
+        c.read_exact(&mut header)?;
 
-        type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
-        let mut cipher = Aes128Ctr::new(DS2_REGULATION_KEY.into(), &iv.into());
+        let mut cipher = Aes128Ctr::new(DS2_REGULATION_KEY.into(), &ds2_iv(&header).into());
         let mut out_buf = Vec::new();
         c.read_to_end(&mut out_buf)?;
         cipher.apply_keystream(out_buf.as_mut_slice());
 
-        BND4::read(&mut Cursor::new(out_buf))
+        Ok(RegulationData {
+            header,
+            data: out_buf,
+        })
+    }
+
+    fn encrypt_regulation_data(regulation: &RegulationData) -> Result<Vec<u8>> {
+        let mut out_buf = match regulation.header.len() {
+            0 => {
+                // Only the first 11 bytes are used for the IV, leave the rest zeroed
+                let mut header = fresh_header(11)?;
+                header.resize(32, 0);
+                header
+            }
+            32 => regulation.header.clone(),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "DS2 regulation header must be 32 bytes long",
+                ))
+            }
+        };
+
+        let mut cipher = Aes128Ctr::new(DS2_REGULATION_KEY.into(), &ds2_iv(&out_buf).into());
+        out_buf.extend_from_slice(&regulation.data);
+        cipher.apply_keystream(&mut out_buf[32..]);
+        Ok(out_buf)
     }
 }
 
-fn decrypt_cbc256_regulation(key: &[u8; 32], encrypted: &[u8]) -> Result<BND4> {
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+
+fn decrypt_cbc256_regulation(key: &[u8; 32], encrypted: &[u8]) -> Result<RegulationData> {
     if BND4::is(encrypted) {
-        let mut c = Cursor::new(encrypted);
-        return Ok(BND4::read(&mut c)?);
+        return Ok(RegulationData {
+            header: Vec::new(),
+            data: encrypted.to_vec(),
+        });
     }
 
     let (iv, data_blob) = encrypted.split_at(16);
-
-    type Aes256Cbc = cbc::Decryptor<aes::Aes256>;
-    let mut cipher = Aes256Cbc::new(key.into(), iv.into());
+    let mut cipher = Aes256CbcDec::new(key.into(), iv.into());
 
     let mut out_buf = data_blob.to_vec();
 
@@ -66,21 +143,130 @@ fn decrypt_cbc256_regulation(key: &[u8; 32], encrypted: &[u8]) -> Result<BND4> {
         cipher.decrypt_blocks_mut(blocks);
     }
 
-    BND4::read(&mut Cursor::new(out_buf))
+    Ok(RegulationData {
+        header: iv.to_vec(),
+        data: out_buf,
+    })
+}
+
+fn encrypt_cbc256_regulation(key: &[u8; 32], regulation: &RegulationData) -> Result<Vec<u8>> {
+    let mut out_buf = match regulation.header.len() {
+        0 => fresh_header(16)?,
+        16 => regulation.header.clone(),
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "regulation IV must be 16 bytes long",
+            ))
+        }
+    };
+    let mut cipher = Aes256CbcEnc::new(key.into(), out_buf.as_slice().into());
+
+    // Zero-pad the data to the block size
+    out_buf.extend_from_slice(&regulation.data);
+    out_buf.resize(16 + regulation.data.len().div_ceil(16) * 16, 0);
+
+    // SAFETY: same align, total size of `blocks` is smaller than `out_buf`
+    unsafe {
+        let (_, blocks, _) = out_buf[16..].align_to_mut::<aes::Block>();
+
+        cipher.encrypt_blocks_mut(blocks);
+    }
+
+    Ok(out_buf)
 }
 
 pub struct DS3;
 impl Game for DS3 {
     const NAME: &'static str = "DS3";
-    fn decrypt_regulation(encrypted: &[u8]) -> Result<BND4> {
+    fn decrypt_regulation_data(encrypted: &[u8]) -> Result<RegulationData> {
         decrypt_cbc256_regulation(DS3_REGULATION_KEY, encrypted)
     }
+
+    fn encrypt_regulation_data(regulation: &RegulationData) -> Result<Vec<u8>> {
+        encrypt_cbc256_regulation(DS3_REGULATION_KEY, regulation)
+    }
 }
 
 pub struct ER;
 impl Game for ER {
     const NAME: &'static str = "ER";
-    fn decrypt_regulation(encrypted: &[u8]) -> Result<BND4> {
+    fn decrypt_regulation_data(encrypted: &[u8]) -> Result<RegulationData> {
         decrypt_cbc256_regulation(ER_REGULATION_KEY, encrypted)
     }
+
+    fn encrypt_regulation_data(regulation: &RegulationData) -> Result<Vec<u8>> {
+        encrypt_cbc256_regulation(ER_REGULATION_KEY, regulation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip<G: Game>(encrypted: &[u8]) {
+        let decrypted = G::decrypt_regulation_data(encrypted).unwrap();
+        let reencrypted = G::encrypt_regulation_data(&decrypted).unwrap();
+        assert!(
+            reencrypted == encrypted,
+            "{} re-encryption differs",
+            G::NAME
+        );
+
+        let redecrypted = G::decrypt_regulation_data(&reencrypted).unwrap();
+        assert_eq!(redecrypted.header, decrypted.header);
+        assert!(redecrypted.data == decrypted.data);
+    }
+
+    #[test]
+    fn er_round_trip() {
+        assert_round_trip::<ER>(&std::fs::read("regulations/er").unwrap());
+    }
+
+    #[test]
+    fn ds3_round_trip() {
+        assert_round_trip::<DS3>(&std::fs::read("regulations/ds3").unwrap());
+    }
+
+    #[test]
+    fn ds3_regulation_round_trip() {
+        let bnd = DS3::decrypt_regulation(&std::fs::read("regulations/ds3").unwrap()).unwrap();
+        let encrypted = DS3::encrypt_regulation(&bnd).unwrap();
+
+        let read = DS3::decrypt_regulation(&encrypted).unwrap();
+        assert_eq!(read.dcx, bnd.dcx);
+        assert!(
+            read.files == bnd.files,
+            "re-encrypted regulation files differ"
+        );
+    }
+
+    #[test]
+    fn ds2_round_trip() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 251) as u8).collect();
+        let encrypted = DS2::encrypt_regulation_data(&RegulationData {
+            header: Vec::new(),
+            data: data.clone(),
+        })
+        .unwrap();
+
+        assert_eq!(encrypted.len(), 32 + data.len());
+        assert!(encrypted[32..] != data[..]);
+        assert_eq!(DS2::decrypt_regulation_data(&encrypted).unwrap().data, data);
+        assert_round_trip::<DS2>(&encrypted);
+    }
+
+    #[test]
+    fn fresh_iv_round_trip() {
+        let data = vec![0x42u8; 100];
+        let encrypted = ER::encrypt_regulation_data(&RegulationData {
+            header: Vec::new(),
+            data: data.clone(),
+        })
+        .unwrap();
+
+        let decrypted = ER::decrypt_regulation_data(&encrypted).unwrap();
+        assert_eq!(&decrypted.data[..100], &data[..]);
+        assert!(decrypted.data[100..].iter().all(|&b| b == 0));
+    }
 }
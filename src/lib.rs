@@ -0,0 +1,20 @@
+pub mod binary_utils;
+pub mod bnd4;
+pub mod codegen;
+pub mod dcx;
+pub mod endian;
+pub mod fixed_str;
+pub mod game;
+#[cfg(feature = "oodle")]
+pub mod oodle;
+pub mod param;
+pub mod paramdex_reader;
+pub mod reflect;
+pub mod row_serde;
+pub mod xml_meta;
+pub mod xml_paramdef;
+
+/// Used by the row name maps of the generated code.
+pub use phf;
+/// Used by the serde implementations of the generated code.
+pub use serde;
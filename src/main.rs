@@ -1,71 +1,429 @@
 use std::{
     collections::HashMap,
-    io::{stdout, Result},
-    marker::PhantomData,
-    ops::{Deref, DerefMut},
-    path::Path,
+    fs,
+    io::{stdout, Write},
+    path::{Path, PathBuf},
 };
 
-mod binary_utils;
-mod bnd4;
-mod codegen;
-mod dcx;
-mod game;
-mod param;
-mod paramdex_reader;
-mod xml_meta;
-mod xml_paramdef;
+use anyhow::{anyhow, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::{info, warn};
 
-use codegen::CodegenParams;
+use paramdef_codegen::{
+    bnd4::BND4,
+    codegen::{CodegenParams, RustCodegen},
+    game::{Game, DS2, DS3, ER},
+    param::ParamFile,
+    paramdex_reader::ParamdexDB,
+};
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Rust code generator and inspector for FromSoftware paramdefs"
+)]
+struct Cli {
+    /// Path to the paramdex folder (containing `Defs`, `Meta`, `Names`, ...)
+    #[arg(long, global = true, default_value = "paramdex")]
+    paramdex: PathBuf,
+
+    /// Log level
+    #[arg(long, global = true, default_value = "info")]
+    log_level: log::Level,
+
+    /// Path to the Oodle shared library (e.g. `oo2core_6_win64.dll`), needed for DCX_KRAK files
+    #[cfg(feature = "oodle")]
+    #[arg(long, global = true)]
+    oodle: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate Rust definitions for one or more params
+    Codegen(CodegenArgs),
+    /// Print the params contained in a regulation file and check them against the paramdefs
+    Dump(RegulationArgs),
+    /// Decrypt a regulation file and extract its contents to a folder
+    Decrypt {
+        #[command(flatten)]
+        regulation: RegulationArgs,
+
+        /// Folder to extract the regulation files to
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+    /// List the paramdefs available in the paramdex and their patch versions
+    List,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GameArg {
+    Ds2,
+    Ds3,
+    Er,
+}
+
+impl GameArg {
+    fn read_regulation(self, path: impl AsRef<Path>) -> Result<BND4> {
+        let bytes = fs::read(path.as_ref())
+            .with_context(|| format!("failed to read {}", path.as_ref().display()))?;
+        Ok(match self {
+            Self::Ds2 => DS2::decrypt_regulation(&bytes),
+            Self::Ds3 => DS3::decrypt_regulation(&bytes),
+            Self::Er => ER::decrypt_regulation(&bytes),
+        }?)
+    }
+}
+
+#[derive(Args)]
+struct RegulationArgs {
+    /// Path to the (encrypted) regulation file
+    #[arg(short, long)]
+    regulation: PathBuf,
+
+    /// Game the regulation file belongs to
+    #[arg(short, long, value_enum)]
+    game: GameArg,
+}
 
-use crate::{bnd4::*, game::*, param::*};
+#[derive(Args)]
+struct CodegenArgs {
+    #[command(flatten)]
+    regulation: RegulationArgs,
 
-fn read_regulation<G: Game>(path: impl AsRef<Path>) -> Result<BND4> {
-    let bytes = std::fs::read(path.as_ref())?;
-    G::decrypt_regulation(bytes.as_slice())
+    /// DefsPatch version to target. Defaults to the latest one
+    #[arg(short = 'v', long = "patch", value_name = "VERSION")]
+    tgt_ver: Option<usize>,
+
+    /// Param type to generate code for. Can be repeated, generating each param in its own module
+    #[arg(short, long = "param", required_unless_present = "all")]
+    params: Vec<String>,
+
+    /// Generate a module tree containing all params instead. `--out` is then the output folder
+    #[arg(long, conflicts_with = "params", requires = "out")]
+    all: bool,
+
+    /// With `--all`, name the root module file `lib.rs` instead of `mod.rs`
+    #[arg(long, requires = "all")]
+    lib: bool,
+
+    /// Output file. Writes to stdout if not provided
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+
+    #[command(flatten)]
+    flags: CodegenFlags,
 }
 
-fn main() {
-    simple_logger::init_with_level(log::Level::Debug).unwrap();
-    let db = paramdex_reader::ParamdexDB::load("paramdex").unwrap();
+/// Command-line switches mapping to the fields of [`CodegenParams`].
+#[derive(Args)]
+struct CodegenFlags {
+    /// Do not generate doc comments, including the paramdef and Meta descriptions
+    #[arg(long)]
+    no_docs: bool,
+
+    /// Use raw integers instead of the Meta enums for field types
+    #[arg(long)]
+    no_field_enums: bool,
+
+    /// Generate enums as newtypes with associated constants, accepting values missing from the Meta
+    #[arg(long, conflicts_with = "no_field_enums")]
+    newtype_enums: bool,
+
+    /// Do not generate row name enums
+    #[arg(long)]
+    no_name_enums: bool,
+
+    /// Use raw integers instead of typed IDs for fields referencing other params or FMGs
+    #[arg(long)]
+    no_ref_ids: bool,
+
+    /// Generate reflection information
+    #[arg(long)]
+    reflection: bool,
+
+    /// Make `dummy8` padding fields public
+    #[arg(long)]
+    public_dummy8: bool,
+
+    /// Generate structs for big endian param files, as found in console games
+    #[arg(long)]
+    big_endian: bool,
+
+    /// Generate serde implementations for the param structs, enums and IDs
+    #[arg(long)]
+    serde: bool,
+
+    /// Generate a module per DefsPatch version of each param, with conversions between them
+    #[arg(long)]
+    versions: bool,
+}
+
+impl From<&CodegenFlags> for CodegenParams {
+    fn from(flags: &CodegenFlags) -> Self {
+        CodegenParams {
+            docs: !flags.no_docs,
+            field_enums: !flags.no_field_enums,
+            newtype_enums: flags.newtype_enums,
+            name_enums: !flags.no_name_enums,
+            ref_ids: !flags.no_ref_ids,
+            reflection: flags.reflection,
+            private_dummy8: !flags.public_dummy8,
+            big_endian: flags.big_endian,
+            serde: flags.serde,
+            versions: flags.versions,
+        }
+    }
+}
+
+fn file_base_name(path: &str) -> &str {
+    let no_ext = path.rsplit_once('.').map_or(path, |(base, _)| base);
+    no_ext.rsplit(['\\', '/']).next().unwrap()
+}
+
+fn codegen(db: &ParamdexDB, args: &CodegenArgs) -> Result<()> {
+    let reg = args
+        .regulation
+        .game
+        .read_regulation(&args.regulation.regulation)?;
+    let tgt_ver = args.tgt_ver.unwrap_or(usize::MAX);
+    let cg = RustCodegen::new(&reg, db, tgt_ver)?;
+    let config = CodegenParams::from(&args.flags);
+
+    if config.field_enums {
+        for diag in cg.enum_diagnostics(&config) {
+            if args.all || args.params.contains(&diag.param) {
+                warn!("{}, using its integer type instead", diag);
+            }
+        }
+    }
+
+    if args.all {
+        let out_dir = args.out.as_ref().unwrap();
+        let root_file_name = if args.lib { "lib.rs" } else { "mod.rs" };
+        cg.gen_all(&config, out_dir, root_file_name)
+            .with_context(|| format!("failed to generate params in {}", out_dir.display()))?;
+        info!("Generated all params in {}", out_dir.display());
+        return Ok(());
+    }
+
+    for param in &args.params {
+        if db.def(param, tgt_ver).is_none() || db.def_meta(param).is_none() {
+            return Err(anyhow!(
+                "No paramdef and meta found for param type {}",
+                param
+            ));
+        }
+    }
+    let params: Vec<_> = args.params.iter().map(String::as_str).collect();
+    let mut out = String::new();
+    cg.gen_params(&params, &config, &mut out)?;
+
+    match &args.out {
+        Some(path) => {
+            fs::write(path, out).with_context(|| format!("failed to write {}", path.display()))?
+        }
+        None => stdout().write_all(out.as_bytes())?,
+    }
+    Ok(())
+}
+
+fn dump(db: &ParamdexDB, args: &RegulationArgs) -> Result<()> {
+    let reg = args.game.read_regulation(&args.regulation)?;
 
     let type_to_def: HashMap<_, _> = db
         .defs_latest()
-        .iter()
-        .map(|(&_, &v)| (&v.param_type, v))
+        .into_values()
+        .map(|v| (v.param_type.as_str(), v))
         .collect();
 
-    let reg = read_regulation::<ER>("regulations/er").unwrap();
-    // for file in &reg.files {
-    //     if let Some(name) = &file.name {
-    //         if !name.ends_with(".param") {
-    //             continue;
-    //         }
-
-    //         let param = ParamFile::new(&file.data).unwrap();
-    //         println!(
-    //             "{}: {}, size {:?}, {} rows",
-    //             name,
-    //             &param.header.param_type,
-    //             param.row_size,
-    //             param.rows.len()
-    //         );
-
-    //         if let Some(sz) = param.row_size {
-    //             let expected_sz = type_to_def
-    //                 .get(&param.header.param_type)
-    //                 .unwrap()
-    //                 .size_bytes
-    //                 .unwrap();
-    //             assert!(expected_sz == sz as usize, "Paramdef size mismatch")
-    //         }
-    //     }
-    // }
-
-    let cg = codegen::RustCodegen::new(&reg, &db, usize::MAX).unwrap();
+    for file in &reg.files {
+        let Some(name) = file.name.as_deref().filter(|n| n.ends_with(".param")) else {
+            continue;
+        };
 
-    let mut out = String::new();
-    cg.gen_paramdef("ActionButtonParam", &CodegenParams::default(), &mut out)
-        .unwrap();
-    std::fs::write("test_param.rs", out).ok();
+        let param = ParamFile::new(&file.data)?;
+        println!(
+            "{}: {}, size {:?}, {} rows",
+            file_base_name(name),
+            &param.header.param_type,
+            param.row_size,
+            param.rows.len()
+        );
+
+        match (
+            type_to_def.get(param.header.param_type.as_str()),
+            param.row_size,
+        ) {
+            (None, _) => warn!("No paramdef found for {}", &param.header.param_type),
+            (Some(def), Some(sz)) if def.size_bytes != Some(sz as usize) => warn!(
+                "Paramdef size mismatch for {}: expected {:?}, got {}",
+                &param.header.param_type, def.size_bytes, sz
+            ),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn decrypt(args: &RegulationArgs, out: &Path) -> Result<()> {
+    let reg = args.game.read_regulation(&args.regulation)?;
+
+    fs::create_dir_all(out)?;
+    for (i, file) in reg.files.iter().enumerate() {
+        let file_name = match &file.name {
+            Some(name) => name.rsplit(['\\', '/']).next().unwrap().to_owned(),
+            None => format!("{}", file.id.unwrap_or(i as u32)),
+        };
+        fs::write(out.join(&file_name), &file.data)?;
+    }
+    info!("Extracted {} files to {}", reg.files.len(), out.display());
+    Ok(())
+}
+
+fn list(db: &ParamdexDB) {
+    let mut names: Vec<_> = db.param_types().collect();
+    names.sort_unstable();
+    for name in names {
+        let versions: Vec<_> = db.def_versions(name).map(|v| v.to_string()).collect();
+        println!("{}: {}", name, versions.join(", "));
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    simple_logger::init_with_level(cli.log_level)?;
+
+    #[cfg(feature = "oodle")]
+    if let Some(path) = &cli.oodle {
+        paramdef_codegen::oodle::OodleKraken::load(path)
+            .with_context(|| format!("failed to load Oodle from {}", path.display()))?
+            .register();
+    }
+
+    let load_db = || ParamdexDB::load(&cli.paramdex);
+    match &cli.command {
+        Command::Codegen(args) => codegen(&load_db()?, args),
+        Command::Dump(args) => dump(&load_db()?, args),
+        Command::Decrypt { regulation, out } => decrypt(regulation, out),
+        Command::List => {
+            list(&load_db()?);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(["paramdef_codegen"].iter().chain(args)).unwrap()
+    }
+
+    #[test]
+    fn parse_codegen() {
+        let cli = parse(&[
+            "codegen",
+            "-r",
+            "regulations/er",
+            "-g",
+            "er",
+            "-p",
+            "ActionButtonParam",
+            "-p",
+            "AtkParam",
+            "--patch",
+            "10701000",
+            "--reflection",
+            "--no-ref-ids",
+            "-o",
+            "out.rs",
+        ]);
+        let Command::Codegen(args) = &cli.command else {
+            panic!("expected the codegen subcommand");
+        };
+        assert_eq!(args.params, ["ActionButtonParam", "AtkParam"]);
+        assert_eq!(args.tgt_ver, Some(10701000));
+        assert_eq!(args.out.as_deref(), Some(Path::new("out.rs")));
+
+        let config = CodegenParams::from(&args.flags);
+        assert!(config.reflection && !config.ref_ids && config.docs);
+
+        let all = Cli::try_parse_from([
+            "paramdef_codegen",
+            "codegen",
+            "-r",
+            "er",
+            "-g",
+            "er",
+            "--all",
+        ]);
+        assert!(all.is_err(), "--all requires --out");
+        let both = Cli::try_parse_from([
+            "paramdef_codegen",
+            "codegen",
+            "-r",
+            "er",
+            "-g",
+            "er",
+            "-p",
+            "AtkParam",
+            "--all",
+            "-o",
+            "out",
+        ]);
+        assert!(both.is_err(), "--all conflicts with --param");
+    }
+
+    #[test]
+    fn parse_dump() {
+        let cli = parse(&[
+            "--paramdex",
+            "pdx",
+            "dump",
+            "-r",
+            "regulations/ds3",
+            "-g",
+            "ds3",
+        ]);
+        assert_eq!(cli.paramdex, Path::new("pdx"));
+        let Command::Dump(args) = &cli.command else {
+            panic!("expected the dump subcommand");
+        };
+        assert_eq!(args.regulation, Path::new("regulations/ds3"));
+        assert!(matches!(args.game, GameArg::Ds3));
+    }
+
+    #[test]
+    fn parse_decrypt() {
+        let cli = parse(&[
+            "decrypt",
+            "-r",
+            "regulations/er",
+            "-g",
+            "er",
+            "-o",
+            "extracted",
+        ]);
+        let Command::Decrypt { regulation, out } = &cli.command else {
+            panic!("expected the decrypt subcommand");
+        };
+        assert!(matches!(regulation.game, GameArg::Er));
+        assert_eq!(out, Path::new("extracted"));
+        assert!(
+            Cli::try_parse_from(["paramdef_codegen", "decrypt", "-r", "er", "-g", "er"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parse_list() {
+        let cli = parse(&["list", "--log-level", "warn"]);
+        assert!(matches!(cli.command, Command::List));
+        assert_eq!(cli.log_level, log::Level::Warn);
+        assert_eq!(cli.paramdex, Path::new("paramdex"));
+    }
 }
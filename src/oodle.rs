@@ -0,0 +1,134 @@
+//! Oodle Kraken codec for `DCX_KRAK`, loaded at runtime from the `oo2core` shared library
+//! shipped with the games.
+use libloading::Library;
+use std::ffi::{c_void, OsStr};
+use std::io::{Error, ErrorKind, Result};
+use std::ptr::{null, null_mut};
+
+use crate::dcx::{DcxCodec, DCX};
+
+type DecompressFn = unsafe extern "C" fn(
+    comp_buf: *const u8,
+    comp_buf_size: isize,
+    raw_buf: *mut u8,
+    raw_len: isize,
+    fuzz_safe: i32,
+    check_crc: i32,
+    verbosity: i32,
+    dec_buf_base: *mut u8,
+    dec_buf_size: isize,
+    fp_callback: *mut c_void,
+    callback_user_data: *mut c_void,
+    decoder_memory: *mut c_void,
+    decoder_memory_size: isize,
+    thread_phase: i32,
+) -> isize;
+
+type CompressFn = unsafe extern "C" fn(
+    compressor: i32,
+    raw_buf: *const u8,
+    raw_len: isize,
+    comp_buf: *mut u8,
+    level: i32,
+    options: *const c_void,
+    dictionary_base: *const u8,
+    lrm: *const c_void,
+    scratch_mem: *mut c_void,
+    scratch_size: isize,
+) -> isize;
+
+const COMPRESSOR_KRAKEN: i32 = 8;
+const THREAD_PHASE_UNTHREADED: i32 = 3;
+
+pub struct OodleKraken {
+    decompress: DecompressFn,
+    compress: CompressFn,
+    _lib: Library,
+}
+
+impl OodleKraken {
+    /// Loads the Oodle shared library at `path` (e.g. `oo2core_6_win64.dll`).
+    pub fn load(path: impl AsRef<OsStr>) -> Result<Self> {
+        // SAFETY: The library is trusted to be Oodle, which exports these functions with the
+        // above signatures. The function pointers do not outlive it as it is kept in `_lib`.
+        unsafe {
+            let lib = Library::new(path).map_err(Error::other)?;
+            let decompress = *lib
+                .get::<DecompressFn>(b"OodleLZ_Decompress\0")
+                .map_err(Error::other)?;
+            let compress = *lib
+                .get::<CompressFn>(b"OodleLZ_Compress\0")
+                .map_err(Error::other)?;
+
+            Ok(OodleKraken {
+                decompress,
+                compress,
+                _lib: lib,
+            })
+        }
+    }
+
+    /// Registers the codec for `DCX_KRAK`.
+    pub fn register(self) {
+        DCX::register_codec(*b"KRAK", self);
+    }
+}
+
+impl DcxCodec for OodleKraken {
+    fn decompress(&self, compressed: &[u8], uncompressed_size: usize) -> Result<Vec<u8>> {
+        let mut out = vec![0u8; uncompressed_size];
+        // SAFETY: Both buffers are valid for the given sizes
+        let size = unsafe {
+            (self.decompress)(
+                compressed.as_ptr(),
+                compressed.len() as isize,
+                out.as_mut_ptr(),
+                out.len() as isize,
+                1,
+                0,
+                0,
+                null_mut(),
+                0,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                0,
+                THREAD_PHASE_UNTHREADED,
+            )
+        };
+
+        if size != uncompressed_size as isize {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Oodle Kraken decompression failed",
+            ));
+        }
+        Ok(out)
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> Result<Vec<u8>> {
+        // Worst case compressed size of the Oodle LZ compressors
+        let mut out = vec![0u8; data.len() + 274 * data.len().div_ceil(0x40000)];
+        // SAFETY: `out` is large enough for any compressed output
+        let size = unsafe {
+            (self.compress)(
+                COMPRESSOR_KRAKEN,
+                data.as_ptr(),
+                data.len() as isize,
+                out.as_mut_ptr(),
+                level as i32,
+                null(),
+                null(),
+                null(),
+                null_mut(),
+                0,
+            )
+        };
+
+        if size <= 0 {
+            return Err(Error::other("Oodle Kraken compression failed"));
+        }
+        out.truncate(size as usize);
+        Ok(out)
+    }
+}
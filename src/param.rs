@@ -1,16 +1,18 @@
-use byteorder::{ByteOrder, ReadBytesExt, BE, LE};
-use std::ffi::CStr;
-use std::{
-    collections::HashMap,
-    io::{
-        Cursor, Error, ErrorKind, Result, Seek,
-        SeekFrom::{self, *},
-    },
-};
-
-use crate::binary_utils::{ByteOrderExt, ReadExt, ReadSliceExt, SeekExt};
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt, BE, LE};
+use std::any::type_name;
+use std::collections::HashMap;
+use std::io::{Cursor, Error, ErrorKind, Result, Seek, SeekFrom::*, Write};
+use std::mem::size_of;
 
+use crate::binary_utils::*;
+
+/// PARAM file header. When writing, the offsets and the row count are recomputed and the layout
+/// is determined by `format_flags_2d`, `is_unicode` and `big_endian`. The param type is written
+/// after the row data, followed by the row names.
+#[derive(Default, Clone, Debug)]
 pub struct Header {
+    /// Not used by the game, which ships params where it points past the end of the file. It is
+    /// kept when writing, unless it points inside the row data.
     pub strings_offset: u32,
     pub short_data_offset: u16,
     pub unk06: u16,
@@ -21,8 +23,13 @@ pub struct Header {
     pub format_flags_2d: u8,
     pub is_64bit: bool,
     pub is_unicode: bool,
+    pub format_flags_2e: u8,
     pub paramdef_version: u8,
+    pub param_type_offset: Option<u64>,
     pub data_offset: Option<u64>,
+    /// Number of zero bytes ending the file after the strings. Most params end with 2 of them, but
+    /// some have none or more, so they are kept when writing.
+    pub end_padding: u64,
 }
 impl Header {
     pub fn new(data: &[u8]) -> Result<Self> {
@@ -36,27 +43,35 @@ impl Header {
 
     fn new_endian<B: ByteOrder>(r: &mut Cursor<&[u8]>) -> Result<Self> {
         let f2d = r.do_at(Start(0x2D), |r| r.read_u8())?;
+        let f2e = r.do_at(Start(0x2E), |r| r.read_u8())?;
+        let strings_offset = r.read_u32::<B>()?;
+        let short_data_offset = r.read_u16::<B>()?;
+        let unk06 = r.read_u16::<B>()?;
+        let paramdef_data_version = r.read_u16::<B>()?;
+        let row_count = r.read_u16::<B>()?;
+        let (param_type, param_type_offset) = if (f2d & Self::FORMAT_OFFSET_PARAM_TYPE) != 0 {
+            r.seek(Current(0x4))?; // Skip padding
+            let ofs = r.read_u64::<B>()?;
+            let t = r.do_at(Start(ofs), |r| r.read_cstring())?;
+            r.seek(Current(0x14))?; // Skip more padding
+            (t, Some(ofs))
+        } else {
+            (r.read_fixed_str(0x20)?.to_owned(), None)
+        };
+
         Ok(Header {
-            strings_offset: r.read_u32::<B>()?,
-            short_data_offset: r.read_u16::<B>()?,
-            unk06: r.read_u16::<B>()?,
-            paramdef_data_version: r.read_u16::<B>()?,
-            row_count: r.read_u16::<B>()?,
-            param_type: {
-                if (f2d & 0x80) != 0 {
-                    r.seek(Current(0x4))?; // Skip padding
-                    let ofs = r.read_u64::<B>()?;
-                    let t = r.do_at(Start(ofs), |r| r.read_cstring())?;
-                    r.seek(Current(0x14))?; // Skip more padding
-                    t
-                } else {
-                    r.read_fixed_str(0x20)?.to_owned()
-                }
-            },
+            strings_offset,
+            short_data_offset,
+            unk06,
+            paramdef_data_version,
+            row_count,
+            param_type,
+            param_type_offset,
             big_endian: r.read_u8()? != 0,
             format_flags_2d: r.read_u8()?,
             is_64bit: (f2d & 4) != 0,
             is_unicode: (r.read_u8()? & 1) != 0,
+            format_flags_2e: f2e,
             paramdef_version: r.read_u8()?,
             data_offset: {
                 if (f2d & 3) == 3 {
@@ -71,8 +86,166 @@ impl Header {
                     None
                 }
             },
+            end_padding: 0,
         })
     }
+
+    /// Size of the header, which depends on how the data offset is stored.
+    fn size(&self) -> u64 {
+        match self.format_flags_2d {
+            f2d if (f2d & 3) == 3 || (f2d & 4) != 0 => 0x40,
+            _ => 0x30,
+        }
+    }
+
+    fn write_endian<B: ByteOrder>(
+        &self,
+        w: &mut impl Write,
+        row_count: u16,
+        data_offset: u64,
+        strings_offset: u64,
+        param_type_offset: u64,
+    ) -> Result<()> {
+        let f2d = self.format_flags_2d;
+        let short_data_offset = match self.size() {
+            0x30 => u16::try_from(data_offset).or(Err(invalid_input(
+                "Param data offset does not fit in 16 bits",
+            )))?,
+            _ => 0,
+        };
+
+        w.write_u32::<B>(strings_offset as u32)?;
+        w.write_u16::<B>(short_data_offset)?;
+        w.write_u16::<B>(self.unk06)?;
+        w.write_u16::<B>(self.paramdef_data_version)?;
+        w.write_u16::<B>(row_count)?;
+        if (f2d & Self::FORMAT_OFFSET_PARAM_TYPE) != 0 {
+            w.write_u32::<B>(0)?;
+            w.write_u64::<B>(param_type_offset)?;
+            w.write_zeros(0x14)?;
+        } else {
+            if !self.param_type.is_ascii() || self.param_type.len() >= 0x20 {
+                return Err(invalid_input("Param type must be ASCII and under 32 bytes"));
+            }
+            w.write_all(self.param_type.as_bytes())?;
+            w.write_zeros(0x20 - self.param_type.len())?;
+        }
+        w.write_u8(if self.big_endian { 0xFF } else { 0 })?;
+        w.write_u8(f2d)?;
+        w.write_u8((self.format_flags_2e & !1) | self.is_unicode as u8)?;
+        w.write_u8(self.paramdef_version)?;
+        if (f2d & 3) == 3 {
+            w.write_u32::<B>(data_offset as u32)?;
+            w.write_zeros(12)?;
+        } else if (f2d & 4) != 0 {
+            w.write_u64::<B>(data_offset)?;
+            w.write_u64::<B>(0)?;
+        }
+        Ok(())
+    }
+
+    /// Size of a row header, i.e. its ID, data offset and name offset.
+    fn row_header_size(&self) -> u64 {
+        match (self.format_flags_2d & 4) != 0 {
+            true => 0x18,
+            false => 0xC,
+        }
+    }
+
+    pub const FORMAT_OFFSET_PARAM_TYPE: u8 = 0b10000000;
+}
+
+fn invalid_input(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidInput, msg)
+}
+
+/// Implemented by the `#[repr(C)]` row structs generated by [`crate::codegen`], so that row data
+/// can be viewed as a typed struct without `unsafe` code.
+///
+/// # Safety
+/// `Self` must be `#[repr(C)]` without padding bytes, and any sized and aligned bytes for which
+/// [`ParamRow::invalid_field`] returns `None` must be a valid value of `Self`.
+pub unsafe trait ParamRow: Sized {
+    /// Whether the multi-byte fields of `Self` are stored big endian. Rows can only be viewed as
+    /// `Self` in param files of the same endianness.
+    const BIG_ENDIAN: bool = false;
+
+    /// Checks the fields which do not accept every bit pattern, such as enums, returning the
+    /// name of the first one holding an invalid value. `data` has the size of `Self`.
+    fn invalid_field(_data: &[u8]) -> Option<&'static str> {
+        None
+    }
+
+    /// Views row data as `Self`, checking its size, alignment and contents.
+    fn from_bytes(data: &[u8]) -> Result<&Self> {
+        check_row_data::<Self>(data)?;
+        // SAFETY: `data` is a valid value of `Self`
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    /// Views the data of a row as `Self`. Errors mention the ID of the row.
+    fn from_row<'a>(row: &Row<'a>) -> Result<&'a Self> {
+        Self::from_bytes(row.data)
+            .map_err(|e| Error::new(e.kind(), format!("Row {}: {}", row.id, e)))
+    }
+
+    fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self> {
+        check_row_data::<Self>(data)?;
+        // SAFETY: `data` is a valid value of `Self`
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `Self` has no padding bytes
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+}
+
+fn check_endianness<T: ParamRow>(header: &Header) -> Result<()> {
+    if header.big_endian != T::BIG_ENDIAN {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "{} is {} endian, but {} is not",
+                &header.param_type,
+                if header.big_endian { "big" } else { "little" },
+                type_name::<T>()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn check_row_data<T: ParamRow>(data: &[u8]) -> Result<()> {
+    if data.len() != size_of::<T>() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Row data is {} bytes, but {} is {} bytes",
+                data.len(),
+                type_name::<T>(),
+                size_of::<T>()
+            ),
+        ));
+    }
+    if !data.as_ptr().cast::<T>().is_aligned() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Row data is not aligned for {}", type_name::<T>()),
+        ));
+    }
+    if let Some(field) = T::invalid_field(data) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Field {} of {} holds a value missing from its enum. Generate newtype enums \
+                 (`--newtype-enums`) to accept any value",
+                field,
+                type_name::<T>()
+            ),
+        ));
+    }
+    Ok(())
 }
 
 pub struct Row<'a> {
@@ -99,22 +272,34 @@ impl<'a> ParamFile<'a> {
     }
 
     fn new_endian<B: ByteOrder + 'static>(r: &mut Cursor<&'a [u8]>) -> Result<Self> {
-        let header = Header::new_endian::<B>(r)?;
+        let mut header = Header::new_endian::<B>(r)?;
         let rows_start = r.position();
 
-        let row_size = (header.row_count >= 2).then_some(match header.is_64bit {
-            true => {
-                let o1 = r.do_at(Start(rows_start + 8), |r| r.read_u64::<B>())?;
-                let o2 = r.do_at(Start(rows_start + 8 + 0x18), |r| r.read_u64::<B>())?;
-                o2 - o1
+        let read_data_ofs = |r: &mut Cursor<&'a [u8]>, index: u64| {
+            let row_start = rows_start + index * header.row_header_size();
+            match header.is_64bit {
+                true => r.do_at(Start(row_start + 8), |r| r.read_u64::<B>()),
+                false => r.do_at(Start(row_start + 4), |r| Ok(r.read_u32::<B>()? as u64)),
             }
-            false => {
-                let o1 = r.do_at(Start(rows_start + 4), |r| r.read_u32::<B>())?;
-                let o2 = r.do_at(Start(rows_start + 8 + 0x18), |r| r.read_u32::<B>())?;
-                (o2 - o1) as u64
+        };
+        let row_size = match header.row_count {
+            0 => None,
+            1 => {
+                // The data of a single row ends where the strings start
+                let end = [header.strings_offset as u64, r.get_ref().len() as u64]
+                    .into_iter()
+                    .chain(header.param_type_offset)
+                    .min()
+                    .unwrap();
+                Some(end.saturating_sub(read_data_ofs(r, 0)?))
             }
-        });
+            _ => Some(read_data_ofs(r, 1)? - read_data_ofs(r, 0)?),
+        };
 
+        // End of the row data and strings, after which the file is padded
+        let mut end = header
+            .param_type_offset
+            .map_or(0, |ofs| ofs + header.param_type.len() as u64 + 1);
         let mut rows = Vec::new();
         for _ in 0..header.row_count {
             let id = r.read_u32::<B>()?;
@@ -130,16 +315,27 @@ impl<'a> ParamFile<'a> {
                 let max_sz = r.get_ref().len() as u64 - r.position();
                 r.read_slice_ref(row_size.unwrap_or(max_sz) as usize)
             })?;
+            end = end.max(data_ofs + data.len() as u64);
 
-            let name = (name_ofs != -1).then_some(r.do_at(Start(name_ofs as u64), |r| {
-                match header.is_unicode {
-                    true => r.read_wide_cstring::<B>(),
-                    false => r.read_cstring(),
+            // Rows without a name have a name offset of 0, or -1 in some files
+            let name = match name_ofs {
+                0 | -1 => None,
+                _ => {
+                    let (name, name_end) = r.do_at(Start(name_ofs as u64), |r| {
+                        let name = match header.is_unicode {
+                            true => r.read_wide_cstring::<B>()?,
+                            false => r.read_shift_jis_cstring()?,
+                        };
+                        Ok((name, r.position()))
+                    })?;
+                    end = end.max(name_end);
+                    Some(name)
                 }
-            })?);
+            };
 
             rows.push(Row { id, name, data });
         }
+        header.end_padding = (r.get_ref().len() as u64).saturating_sub(end);
 
         Ok(ParamFile {
             header,
@@ -148,4 +344,314 @@ impl<'a> ParamFile<'a> {
             data: r.get_ref(),
         })
     }
+
+    /// Views every row as the generated struct `T`, after checking that the row size matches it.
+    pub fn typed_rows<T: ParamRow>(&self) -> Result<Vec<(u32, &'a T)>> {
+        check_endianness::<T>(&self.header)?;
+        if self.row_size.is_some_and(|s| s != size_of::<T>() as u64) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Row size of {} is {:?}, but {} is {} bytes",
+                    &self.header.param_type,
+                    self.row_size,
+                    type_name::<T>(),
+                    size_of::<T>()
+                ),
+            ));
+        }
+        self.rows
+            .iter()
+            .map(|r| Ok((r.id, T::from_row(r)?)))
+            .collect()
+    }
+
+    /// Copies the rows into an [`OwnedParamFile`] which can be modified and written back.
+    pub fn into_owned(self) -> OwnedParamFile {
+        OwnedParamFile {
+            header: self.header,
+            rows: self.rows.into_iter().map(Row::into_owned).collect(),
+        }
+    }
+}
+
+impl Row<'_> {
+    pub fn into_owned(self) -> OwnedRow {
+        OwnedRow {
+            id: self.id,
+            name: self.name,
+            data: self.data.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct OwnedRow {
+    pub id: u32,
+    pub name: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl OwnedRow {
+    /// Creates a row holding the default value of `T`. For the generated param structs, these are
+    /// the default values of the paramdef.
+    pub fn new_default<T: ParamRow + Default>(id: u32, name: Option<String>) -> Self {
+        OwnedRow {
+            id,
+            name,
+            data: T::default().as_bytes().to_vec(),
+        }
+    }
+
+    pub fn get<T: ParamRow>(&self) -> Result<&T> {
+        T::from_bytes(&self.data)
+    }
+
+    pub fn get_mut<T: ParamRow>(&mut self) -> Result<&mut T> {
+        T::from_bytes_mut(&mut self.data)
+    }
+}
+
+/// Owned and mutable version of [`ParamFile`]. Rows are written in order.
+#[derive(Clone, Debug)]
+pub struct OwnedParamFile {
+    pub header: Header,
+    pub rows: Vec<OwnedRow>,
+}
+
+impl OwnedParamFile {
+    pub fn new(data: &[u8]) -> Result<Self> {
+        Ok(ParamFile::new(data)?.into_owned())
+    }
+
+    /// Inserts a new row holding the default value of `T`, keeping the rows sorted by ID, and
+    /// returns it. The file is left unchanged if the default row does not pass the checks of
+    /// [`ParamRow::from_bytes`].
+    pub fn insert_default_row<T: ParamRow + Default>(
+        &mut self,
+        id: u32,
+        name: Option<String>,
+    ) -> Result<&mut T> {
+        check_endianness::<T>(&self.header)?;
+        let index = match self.rows.binary_search_by_key(&id, |r| r.id) {
+            Ok(_) => {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{} already has a row {}", &self.header.param_type, id),
+                ))
+            }
+            Err(index) => index,
+        };
+        if self
+            .rows
+            .first()
+            .is_some_and(|r| r.data.len() != size_of::<T>())
+        {
+            return Err(invalid_input(&format!(
+                "Rows of {} are not {} bytes long",
+                &self.header.param_type,
+                size_of::<T>()
+            )));
+        }
+        let row = OwnedRow::new_default::<T>(id, name);
+        T::from_bytes(&row.data)?;
+        self.rows.insert(index, row);
+        self.rows[index].get_mut()
+    }
+
+    pub fn write(&self, w: &mut impl Write) -> Result<()> {
+        match self.header.big_endian {
+            true => self.write_endian::<BE>(w),
+            false => self.write_endian::<LE>(w),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn write_endian<B: ByteOrder + 'static>(&self, w: &mut impl Write) -> Result<()> {
+        let header = &self.header;
+        let long_offsets = (header.format_flags_2d & 4) != 0;
+        let row_count = u16::try_from(self.rows.len()).or(Err(invalid_input(
+            "Params cannot have more than 65535 rows",
+        )))?;
+
+        // Compute the layout of the file
+        let rows_end = header.size() + self.rows.len() as u64 * header.row_header_size();
+        let data_offset = match header.format_flags_2d {
+            1 => rows_end + 0x20,
+            _ => rows_end,
+        };
+
+        let mut pos = data_offset;
+        let mut row_offsets = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            row_offsets.push(pos);
+            pos += row.data.len() as u64;
+        }
+        let data_end = pos;
+        let strings_offset = data_end.max(header.strings_offset as u64);
+
+        // The param type comes first, directly followed by the names
+        let mut strings = Vec::new();
+        if (header.format_flags_2d & Header::FORMAT_OFFSET_PARAM_TYPE) != 0 {
+            strings.extend(header.param_type.as_bytes());
+            strings.push(0);
+        }
+
+        // Identical names are only written once
+        let mut name_offsets = HashMap::new();
+        for name in self.rows.iter().filter_map(|r| r.name.as_deref()) {
+            if !name_offsets.contains_key(name) {
+                name_offsets.insert(name, data_end + strings.len() as u64);
+                match header.is_unicode {
+                    true => strings.extend(wide_cstring_bytes::<B>(name)),
+                    false => strings.extend(shift_jis_cstring_bytes(name)?),
+                }
+            }
+        }
+
+        // Write everything
+        header.write_endian::<B>(w, row_count, data_offset, strings_offset, data_end)?;
+
+        for (row, &data_ofs) in self.rows.iter().zip(&row_offsets) {
+            let name_ofs = match &row.name {
+                Some(name) => name_offsets[name.as_str()] as i64,
+                None => 0,
+            };
+            w.write_u32::<B>(row.id)?;
+            if long_offsets {
+                w.write_u32::<B>(0)?;
+                w.write_u64::<B>(data_ofs)?;
+                w.write_i64::<B>(name_ofs)?;
+            } else {
+                w.write_u32::<B>(data_ofs as u32)?;
+                w.write_i32::<B>(name_ofs as i32)?;
+            }
+        }
+        w.write_zeros((data_offset - rows_end) as usize)?;
+
+        for row in &self.rows {
+            w.write_all(&row.data)?;
+        }
+        w.write_all(&strings)?;
+        w.write_zeros(header.end_padding as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Game, DS3, ER};
+
+    fn assert_round_trip(param: &OwnedParamFile) {
+        let bytes = param.to_bytes().unwrap();
+        let read = OwnedParamFile::new(&bytes).unwrap();
+
+        let (h1, h2) = (&param.header, &read.header);
+        assert_eq!(h1.param_type, h2.param_type);
+        assert_eq!(h1.unk06, h2.unk06);
+        assert_eq!(h1.paramdef_data_version, h2.paramdef_data_version);
+        assert_eq!(h1.big_endian, h2.big_endian);
+        assert_eq!(h1.format_flags_2d, h2.format_flags_2d);
+        assert_eq!(h1.is_unicode, h2.is_unicode);
+        assert_eq!(h1.paramdef_version, h2.paramdef_version);
+        assert_eq!(h2.row_count as usize, param.rows.len());
+        assert!(param.rows == read.rows, "{} rows differ", h1.param_type);
+
+        // Writing again must be stable
+        assert!(read.to_bytes().unwrap() == bytes);
+    }
+
+    #[test]
+    fn regulation_params_are_written_back_identically() {
+        let er = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+        let ds3 = DS3::decrypt_regulation(&std::fs::read("regulations/ds3").unwrap()).unwrap();
+        for file in er.files.iter().chain(&ds3.files) {
+            let Some(name) = file.name.as_deref().filter(|n| n.ends_with(".param")) else {
+                continue;
+            };
+            let param = OwnedParamFile::new(&file.data).unwrap();
+            assert!(param.to_bytes().unwrap() == file.data, "{} differs", name);
+            assert_round_trip(&param);
+        }
+    }
+
+    #[test]
+    fn header_layouts_round_trip() {
+        let rows = vec![
+            OwnedRow {
+                id: 10,
+                name: Some("名前".to_owned()),
+                data: vec![1, 2, 3, 4, 5, 6, 7, 8],
+            },
+            OwnedRow {
+                id: 20,
+                name: None,
+                data: vec![9; 8],
+            },
+            OwnedRow {
+                id: 5,
+                name: Some("名前".to_owned()),
+                data: vec![0; 8],
+            },
+        ];
+
+        for (format_flags_2d, is_64bit) in [(0, false), (1, false), (3, false), (0x85, true)] {
+            for big_endian in [false, true] {
+                for is_unicode in [false, true] {
+                    let param = OwnedParamFile {
+                        header: Header {
+                            unk06: 3,
+                            paramdef_data_version: 7,
+                            param_type: "TEST_PARAM_ST".to_owned(),
+                            big_endian,
+                            format_flags_2d,
+                            is_64bit,
+                            is_unicode,
+                            paramdef_version: 104,
+                            ..Default::default()
+                        },
+                        rows: rows.clone(),
+                    };
+                    assert_round_trip(&param);
+
+                    // Single row params have no second offset to get the row size from
+                    let mut single = param;
+                    single.rows.truncate(1);
+                    assert_round_trip(&single);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_default_row_is_not_inserted() {
+        #[repr(C)]
+        #[derive(Default)]
+        struct InvalidRow(u32);
+        // SAFETY: `InvalidRow` has no padding and accepts every bit pattern
+        unsafe impl ParamRow for InvalidRow {
+            fn invalid_field(_data: &[u8]) -> Option<&'static str> {
+                Some("value")
+            }
+        }
+
+        let mut param = OwnedParamFile {
+            header: Header {
+                param_type: "TEST_PARAM_ST".to_owned(),
+                ..Default::default()
+            },
+            rows: Vec::new(),
+        };
+        let err = param
+            .insert_default_row::<InvalidRow>(1, None)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(param.rows.is_empty());
+    }
 }
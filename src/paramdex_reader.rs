@@ -1,5 +1,5 @@
-use crate::xml_meta::ParamMeta;
-use crate::xml_paramdef::Paramdef;
+use crate::xml_meta::{ParamEnumOption, ParamMeta, ParamMetaEnum};
+use crate::xml_paramdef::{DefBaseType, Paramdef};
 use anyhow::{anyhow, Result};
 use log::debug;
 use quick_xml::DeError;
@@ -13,6 +13,7 @@ pub struct ParamdexDB {
     paramdefs: HashMap<String, BTreeMap<usize, Paramdef>>,
     param_meta: HashMap<String, ParamMeta>,
     names: HashMap<String, HashMap<u32, String>>,
+    tdfs: HashMap<String, ParamMetaEnum>,
 }
 
 impl ParamdexDB {
@@ -40,7 +41,7 @@ impl ParamdexDB {
                 .to_string_lossy()
         );
         let mut vec = Vec::new();
-        let files = fs::read_dir(path.as_ref())?.into_iter().filter_map(|de| {
+        let files = fs::read_dir(path.as_ref())?.filter_map(|de| {
             let dir = de.ok()?;
             dir.file_type().ok()?.is_file().then_some(dir.path())
         });
@@ -63,7 +64,8 @@ impl ParamdexDB {
             .expect("Impossible program state")
             != 0
         {
-            if let Some((mabye_id, mabye_name)) = string.split_once(" ") {
+            let line = std::mem::take(&mut string);
+            if let Some((mabye_id, mabye_name)) = line.trim_end().split_once(' ') {
                 let id = parse_int::parse(mabye_id).ok().ok_or(std::io::Error::new(
                     io::ErrorKind::InvalidData,
                     "Invalid row ID",
@@ -76,6 +78,47 @@ impl ParamdexDB {
         Ok(hm)
     }
 
+    /// Parses a TDF file: the quoted enum name and integer type on the first two lines, followed
+    /// by one `"Name","Value"` line per option.
+    fn parse_tdf(contents: &str) -> Result<ParamMetaEnum, std::io::Error> {
+        let invalid = |msg: &str| std::io::Error::new(io::ErrorKind::InvalidData, msg.to_owned());
+        let unquote = |s: &str| {
+            let s = s.trim();
+            s.strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .unwrap_or(s)
+                .to_owned()
+        };
+
+        let mut lines = contents
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .filter(|l| !l.trim().is_empty());
+        let name = unquote(lines.next().ok_or_else(|| invalid("Missing TDF name"))?);
+        let base_type = unquote(lines.next().ok_or_else(|| invalid("Missing TDF type"))?)
+            .parse::<DefBaseType>()
+            .map_err(|_| invalid("Invalid TDF type"))?;
+
+        let options = lines
+            .map(|line| {
+                let (name, value) = line
+                    .rsplit_once(',')
+                    .ok_or_else(|| invalid("Invalid TDF option"))?;
+                Ok(ParamEnumOption {
+                    value: parse_int::parse(&unquote(value))
+                        .map_err(|_| invalid("Invalid TDF option value"))?,
+                    name: unquote(name),
+                })
+            })
+            .collect::<Result<_, std::io::Error>>()?;
+
+        Ok(ParamMetaEnum {
+            name,
+            base_type,
+            options,
+        })
+    }
+
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
         Ok(ParamdexDB {
             paramdefs: {
@@ -95,13 +138,11 @@ impl ParamdexDB {
                     if !dir_entry.file_type()?.is_dir() {
                         continue;
                     }
-                    let version = usize::from_str_radix(
-                        dir_entry
-                            .file_name()
-                            .to_str()
-                            .ok_or(anyhow!("file name cannot be converted to UTF8"))?,
-                        10,
-                    )?;
+                    let version: usize = dir_entry
+                        .file_name()
+                        .to_str()
+                        .ok_or(anyhow!("file name cannot be converted to UTF8"))?
+                        .parse()?;
 
                     for (name, def) in Self::load_data_in_folder(
                         dir_entry.path(),
@@ -128,6 +169,13 @@ impl ParamdexDB {
             )?
             .into_iter()
             .collect(),
+            tdfs: match path.as_ref().join("Tdfs") {
+                tdfs if tdfs.is_dir() => Self::load_data_in_folder(tdfs, ".tdf", Self::parse_tdf)?
+                    .into_iter()
+                    .map(|(_, e)| (e.name.clone(), e))
+                    .collect(),
+                _ => HashMap::new(),
+            },
         })
     }
 
@@ -142,10 +190,23 @@ impl ParamdexDB {
     pub fn defs(&self, version: usize) -> HashMap<&str, &Paramdef> {
         self.paramdefs
             .iter()
-            .map(|(ptype, patches)| (ptype.as_str(), patches.range(0..=version).last().unwrap().1))
+            .filter_map(|(ptype, patches)| {
+                Some((ptype.as_str(), patches.range(0..=version).last()?.1))
+            })
             .collect()
     }
 
+    pub fn param_types(&self) -> impl Iterator<Item = &str> {
+        self.paramdefs.keys().map(String::as_str)
+    }
+
+    pub fn def_versions(&self, name: &str) -> impl Iterator<Item = usize> + '_ {
+        self.paramdefs
+            .get(name)
+            .into_iter()
+            .flat_map(|v| v.keys().copied())
+    }
+
     pub fn def_latest(&self, name: &str) -> Option<&Paramdef> {
         self.def(name, usize::MAX)
     }
@@ -171,7 +232,7 @@ impl ParamdexDB {
     }
 
     pub fn param_names(&self) -> impl Iterator<Item = &str> {
-        self.names.keys().into_iter().map(String::as_str)
+        self.names.keys().map(String::as_str)
     }
 
     pub fn row_id_names(&self, param_name: &str) -> Option<&HashMap<u32, String>> {
@@ -181,4 +242,50 @@ impl ParamdexDB {
     pub fn all_row_id_names(&self) -> &HashMap<String, HashMap<u32, String>> {
         &self.names
     }
+
+    /// Returns the enum of the given name defined in the `Tdfs` folder. Unlike Meta enums, these
+    /// are shared by all params.
+    pub fn tdf_enum(&self, name: &str) -> Option<&ParamMetaEnum> {
+        self.tdfs.get(name)
+    }
+
+    pub fn tdf_enums(&self) -> &HashMap<String, ParamMetaEnum> {
+        &self.tdfs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tdf() {
+        let e = ParamdexDB::parse_tdf(
+            "\"ItemType\"\r\n\"u8\"\r\n\"Weapon\",\"0\"\r\n\"Good, Key\",\"3\"\r\n",
+        )
+        .unwrap();
+        assert_eq!(e.name, "ItemType");
+        assert_eq!(e.base_type, DefBaseType::U8);
+        assert_eq!(
+            e.options,
+            [
+                ParamEnumOption {
+                    value: 0,
+                    name: "Weapon".to_owned()
+                },
+                ParamEnumOption {
+                    value: 3,
+                    name: "Good, Key".to_owned()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn load_tdfs() {
+        let db = ParamdexDB::load("paramdex").unwrap();
+        let e = db.tdf_enum("LotItemCategory").unwrap();
+        assert_eq!(e.base_type, DefBaseType::S32);
+        assert_eq!(e.options[1].name, "Good");
+    }
 }
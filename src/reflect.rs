@@ -0,0 +1,231 @@
+use std::io::{Error, ErrorKind, Result};
+use std::mem::size_of;
+
+use crate::param::ParamRow;
+use crate::xml_paramdef::{DefBaseRustType, DefBaseType};
+
+/// Static description of a param struct field, generated when
+/// [`CodegenParams::reflection`](crate::codegen::CodegenParams::reflection) is enabled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldInfo {
+    pub name: &'static str,
+    pub display_name: Option<&'static str>,
+    pub base_type: DefBaseType,
+    /// Offset of the field from the start of the struct, in bits.
+    pub bit_offset: usize,
+    /// Width of the field if it is a bitfield.
+    pub bit_width: Option<usize>,
+    pub array_len: Option<usize>,
+    pub enum_name: Option<&'static str>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub increment: Option<f32>,
+}
+
+impl FieldInfo {
+    /// Byte offset of the field, or of the integer holding it if it is a bitfield.
+    pub fn offset(&self) -> usize {
+        let storage_bits = self.base_type.size_bytes() * 8;
+        (self.bit_offset & !(storage_bits - 1)) / 8
+    }
+}
+
+/// Value of a param field, independent of the struct it belongs to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    F32(f32),
+    Array(Vec<FieldValue>),
+}
+
+impl FieldValue {
+    fn read(t: DefBaseRustType, bytes: &[u8], big_endian: bool) -> FieldValue {
+        let mut bytes = bytes[..t.size_bytes()].to_vec();
+        if big_endian != cfg!(target_endian = "big") {
+            bytes.reverse();
+        }
+        let b2 = || bytes[..2].try_into().unwrap();
+        let b4 = || bytes[..4].try_into().unwrap();
+        match t {
+            DefBaseRustType::U8 => Self::U8(bytes[0]),
+            DefBaseRustType::I8 => Self::I8(bytes[0] as i8),
+            DefBaseRustType::U16 => Self::U16(u16::from_ne_bytes(b2())),
+            DefBaseRustType::I16 => Self::I16(i16::from_ne_bytes(b2())),
+            DefBaseRustType::U32 => Self::U32(u32::from_ne_bytes(b4())),
+            DefBaseRustType::I32 => Self::I32(i32::from_ne_bytes(b4())),
+            DefBaseRustType::F32 => Self::F32(f32::from_ne_bytes(b4())),
+        }
+    }
+
+    fn write(&self, t: DefBaseRustType, bytes: &mut [u8], big_endian: bool) -> Result<()> {
+        let size = t.size_bytes();
+        match (t, self) {
+            (DefBaseRustType::U8, Self::U8(v)) => bytes[..1].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::I8, Self::I8(v)) => bytes[..1].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::U16, Self::U16(v)) => bytes[..2].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::I16, Self::I16(v)) => bytes[..2].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::U32, Self::U32(v)) => bytes[..4].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::I32, Self::I32(v)) => bytes[..4].copy_from_slice(&v.to_ne_bytes()),
+            (DefBaseRustType::F32, Self::F32(v)) => bytes[..4].copy_from_slice(&v.to_ne_bytes()),
+            _ => return Err(type_mismatch(t, self)),
+        }
+        if big_endian != cfg!(target_endian = "big") {
+            bytes[..size].reverse();
+        }
+        Ok(())
+    }
+
+    /// Raw bits of an integer value of type `t`, for bitfields.
+    fn to_bits(&self, t: DefBaseRustType) -> Result<u64> {
+        match (t, self) {
+            (DefBaseRustType::U8, Self::U8(v)) => Ok(*v as u64),
+            (DefBaseRustType::I8, Self::I8(v)) => Ok(*v as u8 as u64),
+            (DefBaseRustType::U16, Self::U16(v)) => Ok(*v as u64),
+            (DefBaseRustType::I16, Self::I16(v)) => Ok(*v as u16 as u64),
+            (DefBaseRustType::U32, Self::U32(v)) => Ok(*v as u64),
+            (DefBaseRustType::I32, Self::I32(v)) => Ok(*v as u32 as u64),
+            _ => Err(type_mismatch(t, self)),
+        }
+    }
+
+    fn from_bits(t: DefBaseRustType, bits: u64) -> FieldValue {
+        match t {
+            DefBaseRustType::U8 => Self::U8(bits as u8),
+            DefBaseRustType::I8 => Self::I8(bits as i8),
+            DefBaseRustType::U16 => Self::U16(bits as u16),
+            DefBaseRustType::I16 => Self::I16(bits as i16),
+            DefBaseRustType::U32 => Self::U32(bits as u32),
+            DefBaseRustType::I32 => Self::I32(bits as i32),
+            DefBaseRustType::F32 => Self::F32(f32::from_bits(bits as u32)),
+        }
+    }
+}
+
+fn type_mismatch(t: DefBaseRustType, value: &FieldValue) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("Cannot assign {:?} to a field of type {}", value, t),
+    )
+}
+
+/// Implemented by the generated param structs when reflection is enabled. Use [`ParamReflect`]
+/// to access the fields.
+pub trait ParamFields: ParamRow {
+    const FIELDS: &'static [FieldInfo];
+}
+
+/// Generic, `dyn` compatible access to the fields of a param struct by name.
+pub trait ParamReflect {
+    fn fields(&self) -> &'static [FieldInfo];
+
+    fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields().iter().find(|f| f.name == name)
+    }
+
+    fn get(&self, name: &str) -> Option<FieldValue>;
+
+    /// Sets the value of a field. Fails if the value has the wrong type, does not fit in a
+    /// bitfield or is not one of the values of the field's enum.
+    fn set(&mut self, name: &str, value: FieldValue) -> Result<()>;
+}
+
+impl<T: ParamFields> ParamReflect for T {
+    fn fields(&self) -> &'static [FieldInfo] {
+        T::FIELDS
+    }
+
+    fn get(&self, name: &str) -> Option<FieldValue> {
+        let field = self.field(name)?;
+        Some(read_field(field, self.as_bytes(), T::BIG_ENDIAN))
+    }
+
+    fn set(&mut self, name: &str, value: FieldValue) -> Result<()> {
+        let field = self.field(name).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} has no field {}", std::any::type_name::<T>(), name),
+            )
+        })?;
+
+        let mut bytes = self.as_bytes().to_vec();
+        write_field(field, &value, &mut bytes, T::BIG_ENDIAN)?;
+        if T::invalid_field(&bytes).is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{:?} is not a valid value of field {}", value, name),
+            ));
+        }
+
+        // SAFETY: `bytes` has the size of `T` and was checked to be a valid value
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), self as *mut T as *mut u8, size_of::<T>())
+        };
+        Ok(())
+    }
+}
+
+fn read_field(field: &FieldInfo, bytes: &[u8], big_endian: bool) -> FieldValue {
+    let t = field.base_type.rust_type();
+    let offset = field.offset();
+    if let Some(width) = field.bit_width {
+        let storage = FieldValue::read(t, &bytes[offset..], big_endian)
+            .to_bits(t)
+            .unwrap();
+        let shift = field.bit_offset - offset * 8;
+        return FieldValue::from_bits(t, (storage >> shift) & ((1 << width) - 1));
+    }
+    match field.array_len {
+        Some(len) => FieldValue::Array(
+            (0..len)
+                .map(|i| FieldValue::read(t, &bytes[offset + i * t.size_bytes()..], big_endian))
+                .collect(),
+        ),
+        None => FieldValue::read(t, &bytes[offset..], big_endian),
+    }
+}
+
+fn write_field(
+    field: &FieldInfo,
+    value: &FieldValue,
+    bytes: &mut [u8],
+    big_endian: bool,
+) -> Result<()> {
+    let t = field.base_type.rust_type();
+    let offset = field.offset();
+    if let Some(width) = field.bit_width {
+        let bits = value.to_bits(t)?;
+        let mask = (1u64 << width) - 1;
+        if bits > mask {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "{:?} does not fit in the {} bits of field {}",
+                    value, width, field.name
+                ),
+            ));
+        }
+
+        let shift = field.bit_offset - offset * 8;
+        let storage = FieldValue::read(t, &bytes[offset..], big_endian).to_bits(t)?;
+        let storage = (storage & !(mask << shift)) | (bits << shift);
+        return FieldValue::from_bits(t, storage).write(t, &mut bytes[offset..], big_endian);
+    }
+    match (field.array_len, value) {
+        (Some(len), FieldValue::Array(values)) if values.len() == len => {
+            for (i, v) in values.iter().enumerate() {
+                v.write(t, &mut bytes[offset + i * t.size_bytes()..], big_endian)?;
+            }
+            Ok(())
+        }
+        (Some(len), _) => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Field {} is an array of {} values", field.name, len),
+        )),
+        (None, _) => value.write(t, &mut bytes[offset..], big_endian),
+    }
+}
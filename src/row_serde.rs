@@ -0,0 +1,146 @@
+use std::fmt;
+
+use serde::de::{self, IntoDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Implemented by the generated param structs when
+/// [`CodegenParams::serde`](crate::codegen::CodegenParams::serde) is enabled, to deserialize
+/// fields one by one into an existing row.
+pub trait DeserializeFields {
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// Reads the next value of `map` into the field `name`. Returns false if there is no such
+    /// field.
+    fn deserialize_field<'de, A: MapAccess<'de>>(
+        &mut self,
+        name: &str,
+        map: &mut A,
+    ) -> Result<bool, A::Error>;
+}
+
+/// Deserializes the fields of a row into `row`. Padding and fields missing from the input keep
+/// their current value.
+pub fn deserialize_into<'de, T: DeserializeFields, D: Deserializer<'de>>(
+    row: &mut T,
+    deserializer: D,
+) -> Result<(), D::Error> {
+    deserializer.deserialize_map(RowVisitor(row))
+}
+
+struct RowVisitor<'a, T>(&'a mut T);
+
+impl<'de, T: DeserializeFields> Visitor<'de> for RowVisitor<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a {} row", std::any::type_name::<T>())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(name) = map.next_key::<String>()? {
+            if !self.0.deserialize_field(&name, &mut map)? {
+                return Err(de::Error::unknown_field(&name, T::FIELD_NAMES));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Converts the values of an array field, which serde only supports up to 32 elements.
+pub fn to_array<T, E: de::Error, const N: usize>(values: Vec<T>) -> Result<[T; N], E> {
+    let len = values.len();
+    values
+        .try_into()
+        .map_err(|_| E::invalid_length(len, &format!("{} values", N).as_str()))
+}
+
+/// Serializes an enum value by name, or as a number if it has no name which can be read back
+/// unambiguously.
+pub fn serialize_enum<T: Copy + PartialEq + Serialize, S: Serializer>(
+    options: &[(&str, T)],
+    value: T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let name = options.iter().find(|(_, v)| *v == value).map(|(n, _)| *n);
+    match name {
+        Some(name) if !name.is_empty() && enum_value(options, name) == Some(value) => {
+            serializer.serialize_str(name)
+        }
+        _ => value.serialize(serializer),
+    }
+}
+
+/// Deserializes an enum value from either one of its names or a number.
+pub fn deserialize_enum<'de, T: Copy + Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+    enum_name: &str,
+    options: &[(&str, T)],
+) -> Result<T, D::Error> {
+    deserializer.deserialize_any(EnumVisitor { enum_name, options })
+}
+
+fn enum_value<T: Copy>(options: &[(&str, T)], name: &str) -> Option<T> {
+    options.iter().find(|(n, _)| *n == name).map(|(_, v)| *v)
+}
+
+struct EnumVisitor<'a, T> {
+    enum_name: &'a str,
+    options: &'a [(&'a str, T)],
+}
+
+impl<'de, T: Copy + Deserialize<'de>> Visitor<'de> for EnumVisitor<'_, T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a name or value of {}", self.enum_name)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        enum_value(self.options, v)
+            .ok_or_else(|| E::custom(format!("unknown {} name {:?}", self.enum_name, v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        T::deserialize(v.into_deserializer())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        T::deserialize(v.into_deserializer())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        T::deserialize(v.into_deserializer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::value::{Error, StrDeserializer, U64Deserializer};
+
+    const OPTIONS: &[(&str, u8)] = &[("None", 0), ("Fire", 1), ("None", 2), ("", 3)];
+
+    #[test]
+    fn enums_by_name_with_numeric_fallback() {
+        let from_str = |s| deserialize_enum(StrDeserializer::<Error>::new(s), "ELEMENT", OPTIONS);
+        assert_eq!(from_str("Fire"), Ok(1));
+        assert!(from_str("Water").is_err());
+        let from_u64 = |v| deserialize_enum(U64Deserializer::<Error>::new(v), "ELEMENT", OPTIONS);
+        assert_eq!(from_u64(2), Ok(2));
+        assert!(from_u64(256).is_err());
+    }
+
+    #[test]
+    fn ambiguous_names_are_serialized_as_numbers() {
+        let to_json = |v| {
+            let mut json = Vec::new();
+            serialize_enum(OPTIONS, v, &mut serde_json::Serializer::new(&mut json)).unwrap();
+            String::from_utf8(json).unwrap()
+        };
+        assert_eq!(to_json(0), "\"None\"");
+        assert_eq!(to_json(1), "\"Fire\"");
+        assert_eq!(to_json(2), "2");
+        assert_eq!(to_json(3), "3");
+        assert_eq!(to_json(4), "4");
+    }
+}
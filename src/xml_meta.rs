@@ -4,7 +4,7 @@ use serde::{
     Deserialize,
 };
 use serde_derive::Deserialize;
-use std::{collections::HashMap, marker::PhantomData};
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename = "PARAMMETA", rename_all = "PascalCase")]
@@ -51,7 +51,7 @@ impl std::ops::Deref for ParamMetaEnums {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct ParamMetaEnum {
     #[serde(rename = "@Name")]
     pub name: String,
@@ -61,7 +61,7 @@ pub struct ParamMetaEnum {
     pub options: Vec<ParamEnumOption>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct ParamEnumOption {
     #[serde(rename = "@Value")]
     pub value: i64,
@@ -75,10 +75,67 @@ pub struct ParamMetaField {
     pub alt_name: String,
     #[serde(rename = "@Wiki")]
     pub wiki: Option<String>,
-    #[serde(rename = "@EnumName")]
+    #[serde(rename = "@Enum", alias = "@EnumName")]
     pub enum_name: Option<String>,
     #[serde(default, rename = "@IsBool", deserialize_with = "is_tag_present")]
     pub is_bool: bool,
+    #[serde(default, rename = "@Refs", deserialize_with = "param_refs")]
+    pub refs: Vec<ParamRef>,
+    #[serde(rename = "@VRef")]
+    pub vref: Option<String>,
+    #[serde(rename = "@FmgRef")]
+    pub fmg_ref: Option<String>,
+}
+
+impl ParamMetaField {
+    /// Returns the param this field always holds a row ID of, if any.
+    pub fn ref_param(&self) -> Option<&str> {
+        match self.refs.as_slice() {
+            [r] if r.condition.is_none() => Some(&r.param),
+            _ => None,
+        }
+    }
+}
+
+/// Entry of the `Refs` attribute of a field, such as `Bullet(refCategory=1)`: the field is a row
+/// ID of `param`, when the optional `condition` field of the row has the given value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParamRef {
+    pub param: String,
+    pub condition: Option<(String, i64)>,
+}
+
+impl FromStr for ParamRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some((param, condition)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) else {
+            return Ok(ParamRef {
+                param: s.to_owned(),
+                condition: None,
+            });
+        };
+        let (field, value) = condition
+            .split_once('=')
+            .and_then(|(f, v)| Some((f.trim(), v.trim().parse().ok()?)))
+            .ok_or_else(|| format!("invalid param reference condition: {}", condition))?;
+        Ok(ParamRef {
+            param: param.trim().to_owned(),
+            condition: Some((field.to_owned(), value)),
+        })
+    }
+}
+
+fn param_refs<'de, D>(deserializer: D) -> Result<Vec<ParamRef>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let refs: String = de::Deserialize::deserialize(deserializer)?;
+    refs.split(',')
+        .filter(|r| !r.trim().is_empty())
+        .map(|r| r.parse().map_err(de::Error::custom))
+        .collect()
 }
 
 fn is_tag_present<'de, D>(_deserializer: D) -> Result<bool, D::Error>
@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -22,7 +22,9 @@ pub struct Paramdef {
 impl Paramdef {
     pub fn compute_field_offsets(mut self) -> Self {
         let mut bit_offset: usize = 0;
-        self.fields.first_mut().map(|f| f.bit_offset = Some(0));
+        if let Some(f) = self.fields.first_mut() {
+            f.bit_offset = Some(0);
+        }
         for i in 1..self.fields.len() {
             bit_offset = self.fields[i]
                 .field_def
@@ -30,10 +32,10 @@ impl Paramdef {
             self.fields[i].bit_offset = Some(bit_offset);
         }
         // Align bit offset to last field's size
-        self.fields.last().map(|f| {
+        if let Some(f) = self.fields.last() {
             let a = 8 * f.alignment();
             bit_offset = (bit_offset + f.size_bits() + a - 1) & !(a - 1);
-        });
+        }
         self.size_bytes = Some(bit_offset / 8);
         self
     }
@@ -93,6 +95,10 @@ impl DefField {
     pub fn size_bits(&self) -> usize {
         self.field_def.size_bits()
     }
+
+    pub fn default_value(&self) -> Option<f64> {
+        self.field_def.default_value
+    }
 }
 
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
@@ -175,20 +181,24 @@ impl DefBaseType {
     pub fn alignment(&self) -> usize {
         self.rust_type().alignment()
     }
+}
+
+impl FromStr for DefBaseType {
+    type Err = ();
 
-    pub fn from_str(s: &str) -> Option<DefBaseType> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "dummy8" => Some(Self::Dummy8),
-            "s8" => Some(Self::S8),
-            "u8" => Some(Self::U8),
-            "s16" => Some(Self::S16),
-            "u16" => Some(Self::U16),
-            "s32" => Some(Self::S32),
-            "u32" => Some(Self::U32),
-            "f32" => Some(Self::F32),
-            "fixstr" => Some(Self::Fixstr),
-            "fixstrW" => Some(Self::FixstrW),
-            _ => None,
+            "dummy8" => Ok(Self::Dummy8),
+            "s8" => Ok(Self::S8),
+            "u8" => Ok(Self::U8),
+            "s16" => Ok(Self::S16),
+            "u16" => Ok(Self::U16),
+            "s32" => Ok(Self::S32),
+            "u32" => Ok(Self::U32),
+            "f32" => Ok(Self::F32),
+            "fixstr" => Ok(Self::Fixstr),
+            "fixstrW" => Ok(Self::FixstrW),
+            _ => Err(()),
         }
     }
 }
@@ -201,25 +211,21 @@ pub enum DefTypeModifier {
 }
 impl DefTypeModifier {
     pub fn is_array(&self) -> bool {
-        match *self {
-            Self::Array(_) => true,
-            _ => false,
-        }
+        matches!(*self, Self::Array(_))
     }
 
     pub fn is_bitfield(&self) -> bool {
-        match *self {
-            Self::Bitfield(_) => true,
-            _ => false,
-        }
+        matches!(*self, Self::Bitfield(_))
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct DefType {
     pub name: String,
     pub base_type: DefBaseType,
     pub modifier: DefTypeModifier,
+    /// Value of the field in new rows, from the `= value` suffix of the definition.
+    pub default_value: Option<f64>,
 }
 
 impl DefType {
@@ -262,7 +268,7 @@ impl DefType {
 
         // Otherwise, add the size of the previous one, and align
         let next_offset = prev_offset + prev_field.size_bits();
-        next_offset + (self.alignment_bits() - 1) & !(self.alignment_bits() - 1)
+        (next_offset + (self.alignment_bits() - 1)) & !(self.alignment_bits() - 1)
     }
 }
 
@@ -273,7 +279,7 @@ impl<'de> Deserialize<'de> for DefType {
     {
         lazy_static! {
             static ref FIELD_PARSE: Regex = Regex::new(
-                r"^(?P<base_type>[\w\d_]+)\s+(?P<name>[\w\d_]+)\s*((\[(?P<array_size>[\w\d]+)\])|(:\s*(?P<bitfield_size>[\w\d]+)))?\s*(=.*)?$"
+                r"^(?P<base_type>[\w\d_]+)\s+(?P<name>[\w\d_]+)\s*((\[(?P<array_size>[\w\d]+)\])|(:\s*(?P<bitfield_size>[\w\d]+)))?\s*(=\s*(?P<default_value>\S+)\s*)?$"
             ).unwrap();
         }
 
@@ -287,10 +293,10 @@ impl<'de> Deserialize<'de> for DefType {
             name: captures.name("name").unwrap().as_str().to_owned(),
             base_type: {
                 let base_type_str = captures.name("base_type").unwrap().as_str();
-                DefBaseType::from_str(base_type_str).ok_or(de::Error::invalid_value(
+                DefBaseType::from_str(base_type_str).or(Err(de::Error::invalid_value(
                     de::Unexpected::Str(base_type_str),
                     &"paramdef field type",
-                ))?
+                )))?
             },
             modifier: {
                 let parse_int = |s: &str| {
@@ -308,6 +314,13 @@ impl<'de> Deserialize<'de> for DefType {
                     DefTypeModifier::None
                 }
             },
+            default_value: match captures.name("default_value") {
+                Some(value) => Some(value.as_str().parse().or(Err(de::Error::invalid_value(
+                    de::Unexpected::Str(value.as_str()),
+                    &"number",
+                )))?),
+                None => None,
+            },
         })
     }
 }
@@ -0,0 +1,37 @@
+//! Reads the DCX fixtures in `tests/fixtures`, which were compressed independently of this crate.
+use std::io::{Cursor, Seek, SeekFrom};
+
+use paramdef_codegen::{binary_utils::SeekExt, dcx::DCX};
+
+fn fixture_data() -> Vec<u8> {
+    (0..100)
+        .flat_map(|i| format!("DCP_DFLT fixture line {:03}\n", i).into_bytes())
+        .collect()
+}
+
+#[test]
+fn dcp_dflt_decompress() {
+    let dcp = std::fs::read("tests/fixtures/dcp_dflt.bin").unwrap();
+    assert!(DCX::is(&dcp));
+    let data = DCX::decompress(&mut Cursor::new(dcp.as_slice())).unwrap();
+    assert!(data == fixture_data(), "DCP_DFLT decompression differs");
+}
+
+#[test]
+fn dcp_dflt_decompress_at_offset() {
+    let mut file = vec![0xFFu8; 0x30];
+    file.extend(std::fs::read("tests/fixtures/dcp_dflt.bin").unwrap());
+
+    let mut c = Cursor::new(file.as_slice());
+    c.seek(SeekFrom::Start(0x30)).unwrap();
+    let data = DCX::decompress(&mut c).unwrap();
+    assert!(data == fixture_data(), "DCP_DFLT decompression differs");
+}
+
+#[test]
+fn stream_len_is_remaining_length() {
+    let mut c = Cursor::new([0u8; 0x40]);
+    c.seek(SeekFrom::Start(0x10)).unwrap();
+    assert_eq!(c.my_stream_len().unwrap(), 0x30);
+    assert_eq!(c.stream_position().unwrap(), 0x10);
+}
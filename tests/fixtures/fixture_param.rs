@@ -0,0 +1,226 @@
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemType {
+/// Weapon
+Weapon = 0,
+/// Protector
+Protector = 1,
+/// Accessory
+Accessory = 2,
+/// Good
+Good = 3,
+/// Gem
+Gem = 4,
+}
+impl ::std::fmt::Display for ItemType {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::Weapon => "Weapon",
+Self::Protector => "Protector",
+Self::Accessory => "Accessory",
+Self::Good => "Good",
+Self::Gem => "Gem",
+})
+}
+}
+impl TryFrom<u8> for ItemType {
+type Error = u8;
+fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+match value {
+0 => Ok(Self::Weapon),
+1 => Ok(Self::Protector),
+2 => Ok(Self::Accessory),
+3 => Ok(Self::Good),
+4 => Ok(Self::Gem),
+_ => Err(value),
+}
+}
+}
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LotItemCategory {
+/// None
+None = 0,
+/// Good
+Good = 1,
+/// Weapon
+Weapon = 2,
+/// Protector
+Protector = 3,
+/// Accessory
+Accessory = 4,
+/// Gem
+Gem = 5,
+}
+impl ::std::fmt::Display for LotItemCategory {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::None => "None",
+Self::Good => "Good",
+Self::Weapon => "Weapon",
+Self::Protector => "Protector",
+Self::Accessory => "Accessory",
+Self::Gem => "Gem",
+})
+}
+}
+impl TryFrom<i32> for LotItemCategory {
+type Error = i32;
+fn try_from(value: i32) -> ::std::result::Result<Self, i32> {
+match value {
+0 => Ok(Self::None),
+1 => Ok(Self::Good),
+2 => Ok(Self::Weapon),
+3 => Ok(Self::Protector),
+4 => Ok(Self::Accessory),
+5 => Ok(Self::Gem),
+_ => Err(value),
+}
+}
+}
+/// Paramdef used by the tests to cover features which no paramdex param uses.
+#[repr(C)]
+pub struct FIXTURE_PARAM_ST {
+/// Shared TDF enum with a u8 base type.
+/// ### Display Name
+/// Item type
+pub itemType: ItemType,
+/// ### Display Name
+/// Padding
+pad0: [u8; 3],
+/// Shared TDF enum with an s32 base type.
+/// ### Display Name
+/// Lot item category
+pub lotItemCategory: LotItemCategory,
+/// UTF-16 string.
+/// ### Display Name
+/// Wide name
+pub wideName: ::paramdef_codegen::fixed_str::FixedWStr<8>,
+/// Shift-JIS string.
+/// ### Display Name
+/// Name
+pub name: ::paramdef_codegen::fixed_str::FixedStr<16>,
+/// Boolean stored in a plain u8.
+/// ### Display Name
+/// Enable parry
+pub isEnableParry: u8,
+/// ### Display Name
+/// pad
+pad1: [u8; 3],
+_bitfield1: [u8; 4],
+}
+impl FIXTURE_PARAM_ST {
+/// Boolean stored in a plain u8.
+/// ### Display Name
+/// Enable parry
+pub fn isEnableParry_get(&self) -> bool {
+self.isEnableParry != 0
+}
+/// Boolean stored in a plain u8.
+/// ### Display Name
+/// Enable parry
+pub fn isEnableParry_set(&mut self, value: bool) {
+self.isEnableParry = value as u8;
+}
+/// Enum stored in a multi-byte bitfield.
+/// ### Display Name
+/// Lot item category bits
+pub fn lotCategoryBits_get(&self) -> LotItemCategory {
+let storage = u32::from_le_bytes(self._bitfield1[0..4].try_into().unwrap());
+let bits = (storage & 255u32) >> 0;
+LotItemCategory::try_from(bits as i32).unwrap()
+}
+/// Enum stored in a multi-byte bitfield.
+/// ### Display Name
+/// Lot item category bits
+/// ### Errors
+/// If the value does not fit in 8 bits.
+pub fn lotCategoryBits_set(&mut self, value: LotItemCategory) -> ::std::io::Result<()> {
+let bits: u32 = value as _;
+if bits & !255u32 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 8 bits of lotCategoryBits", value),
+));
+}
+let storage = u32::from_le_bytes(self._bitfield1[0..4].try_into().unwrap());
+self._bitfield1[0..4].copy_from_slice(&(storage & !255u32 | bits << 0).to_le_bytes());
+Ok(())
+}
+/// Enum stored in a multi-byte bitfield.
+/// ### Display Name
+/// Lot item category bits
+pub fn lotCategoryBits_update(&mut self, f: impl Fn(LotItemCategory) -> LotItemCategory) -> ::std::io::Result<()> {
+self.lotCategoryBits_set(f(self.lotCategoryBits_get()))
+}
+/// ### Display Name
+/// Reserved bits
+pub fn reserved_get(&self) -> i32 {
+let storage = u32::from_le_bytes(self._bitfield1[0..4].try_into().unwrap());
+let bits = (storage & 4294967040u32) >> 8;
+bits as i32
+}
+/// ### Display Name
+/// Reserved bits
+/// ### Errors
+/// If the value does not fit in 24 bits.
+pub fn reserved_set(&mut self, value: i32) -> ::std::io::Result<()> {
+let bits: u32 = value as _;
+if bits & !16777215u32 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 24 bits of reserved", value),
+));
+}
+let storage = u32::from_le_bytes(self._bitfield1[0..4].try_into().unwrap());
+self._bitfield1[0..4].copy_from_slice(&(storage & !4294967040u32 | bits << 8).to_le_bytes());
+Ok(())
+}
+/// ### Display Name
+/// Reserved bits
+pub fn reserved_update(&mut self, f: impl Fn(i32) -> i32) -> ::std::io::Result<()> {
+self.reserved_set(f(self.reserved_get()))
+}
+}
+impl Default for FIXTURE_PARAM_ST {
+fn default() -> Self {
+Self {
+itemType: ItemType::Weapon,
+pad0: [0; 3],
+lotItemCategory: LotItemCategory::None,
+wideName: Default::default(),
+name: Default::default(),
+isEnableParry: 0,
+pad1: [0; 3],
+_bitfield1: [0; 4]
+}
+}
+}
+const _: () = assert!(::std::mem::size_of::<FIXTURE_PARAM_ST>() == 48);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, itemType) == 0);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, pad0) == 1);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, lotItemCategory) == 4);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, wideName) == 8);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, name) == 24);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, isEnableParry) == 40);
+const _: () = assert!(::std::mem::offset_of!(FIXTURE_PARAM_ST, pad1) == 41);
+unsafe impl ::paramdef_codegen::param::ParamRow for FIXTURE_PARAM_ST {
+fn invalid_field(data: &[u8]) -> ::std::option::Option<&'static str> {
+if !matches!(u8::from_ne_bytes(data[0..0 + 1].try_into().unwrap()), 0 | 1 | 2 | 3 | 4) {
+return Some("itemType");
+}
+if !matches!(i32::from_ne_bytes(data[4..4 + 4].try_into().unwrap()), 0 | 1 | 2 | 3 | 4 | 5) {
+return Some("lotItemCategory");
+}
+if !matches!((i32::from_le_bytes(data[44..44 + 4].try_into().unwrap()) & 255i32) >> 0, 0 | 1 | 2 | 3 | 4 | 5) {
+return Some("lotCategoryBits");
+}
+None
+}
+}
+impl<'a> TryFrom<&::paramdef_codegen::param::Row<'a>> for &'a FIXTURE_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(row: &::paramdef_codegen::param::Row<'a>) -> ::std::io::Result<Self> {
+::paramdef_codegen::param::ParamRow::from_row(row)
+}
+}
@@ -0,0 +1,2065 @@
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACTION_BUTTON_REGION_TYPE {
+/// Cylinder
+Cylinder = 0,
+/// Prism
+Prism = 1,
+/// Capsule
+Capsule = 2,
+}
+impl ::std::fmt::Display for ACTION_BUTTON_REGION_TYPE {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::Cylinder => "Cylinder",
+Self::Prism => "Prism",
+Self::Capsule => "Capsule",
+})
+}
+}
+impl TryFrom<u8> for ACTION_BUTTON_REGION_TYPE {
+type Error = u8;
+fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+match value {
+0 => Ok(Self::Cylinder),
+1 => Ok(Self::Prism),
+2 => Ok(Self::Capsule),
+_ => Err(value),
+}
+}
+}
+impl ACTION_BUTTON_REGION_TYPE {
+const SERDE_OPTIONS: &'static [(&'static str, u8)] = &[
+("Cylinder", 0),
+("Prism", 1),
+("Capsule", 2),
+];
+}
+impl ::paramdef_codegen::serde::Serialize for ACTION_BUTTON_REGION_TYPE {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+::paramdef_codegen::row_serde::serialize_enum(Self::SERDE_OPTIONS, *self as u8, serializer)
+}
+}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for ACTION_BUTTON_REGION_TYPE {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+let value = ::paramdef_codegen::row_serde::deserialize_enum(deserializer, "ACTION_BUTTON_REGION_TYPE", Self::SERDE_OPTIONS)?;
+Self::try_from(value).map_err(|v| {
+<D::Error as ::paramdef_codegen::serde::de::Error>::custom(format!("{} is not a value of ACTION_BUTTON_REGION_TYPE", v))
+})
+}
+}
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACTION_BUTTON_CATEGORY {
+/// Category 0
+Category0 = 0,
+/// Category 1
+Category1 = 1,
+/// Category 2
+Category2 = 2,
+/// Category 3
+Category3 = 3,
+/// Category 4
+Category4 = 4,
+/// Category 5
+Category5 = 5,
+/// Category 6
+Category6 = 6,
+/// Category 7
+Category7 = 7,
+/// Category 8
+Category8 = 8,
+/// Category 9
+Category9 = 9,
+/// Category 10
+Category10 = 10,
+/// Category 11
+Category11 = 11,
+/// Category 12
+Category12 = 12,
+/// Category 14
+Category14 = 14,
+}
+impl ::std::fmt::Display for ACTION_BUTTON_CATEGORY {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::Category0 => "Category 0",
+Self::Category1 => "Category 1",
+Self::Category2 => "Category 2",
+Self::Category3 => "Category 3",
+Self::Category4 => "Category 4",
+Self::Category5 => "Category 5",
+Self::Category6 => "Category 6",
+Self::Category7 => "Category 7",
+Self::Category8 => "Category 8",
+Self::Category9 => "Category 9",
+Self::Category10 => "Category 10",
+Self::Category11 => "Category 11",
+Self::Category12 => "Category 12",
+Self::Category14 => "Category 14",
+})
+}
+}
+impl TryFrom<u8> for ACTION_BUTTON_CATEGORY {
+type Error = u8;
+fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+match value {
+0 => Ok(Self::Category0),
+1 => Ok(Self::Category1),
+2 => Ok(Self::Category2),
+3 => Ok(Self::Category3),
+4 => Ok(Self::Category4),
+5 => Ok(Self::Category5),
+6 => Ok(Self::Category6),
+7 => Ok(Self::Category7),
+8 => Ok(Self::Category8),
+9 => Ok(Self::Category9),
+10 => Ok(Self::Category10),
+11 => Ok(Self::Category11),
+12 => Ok(Self::Category12),
+14 => Ok(Self::Category14),
+_ => Err(value),
+}
+}
+}
+impl ACTION_BUTTON_CATEGORY {
+const SERDE_OPTIONS: &'static [(&'static str, u8)] = &[
+("Category 0", 0),
+("Category 1", 1),
+("Category 2", 2),
+("Category 3", 3),
+("Category 4", 4),
+("Category 5", 5),
+("Category 6", 6),
+("Category 7", 7),
+("Category 8", 8),
+("Category 9", 9),
+("Category 10", 10),
+("Category 11", 11),
+("Category 12", 12),
+("Category 14", 14),
+];
+}
+impl ::paramdef_codegen::serde::Serialize for ACTION_BUTTON_CATEGORY {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+::paramdef_codegen::row_serde::serialize_enum(Self::SERDE_OPTIONS, *self as u8, serializer)
+}
+}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for ACTION_BUTTON_CATEGORY {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+let value = ::paramdef_codegen::row_serde::deserialize_enum(deserializer, "ACTION_BUTTON_CATEGORY", Self::SERDE_OPTIONS)?;
+Self::try_from(value).map_err(|v| {
+<D::Error as ::paramdef_codegen::serde::de::Error>::custom(format!("{} is not a value of ACTION_BUTTON_CATEGORY", v))
+})
+}
+}
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACTION_BUTTON_ANGLE_CHECK_TYPE {
+/// Type 0
+Type0 = 0,
+/// Type 1
+Type1 = 1,
+}
+impl ::std::fmt::Display for ACTION_BUTTON_ANGLE_CHECK_TYPE {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::Type0 => "Type 0",
+Self::Type1 => "Type 1",
+})
+}
+}
+impl TryFrom<u8> for ACTION_BUTTON_ANGLE_CHECK_TYPE {
+type Error = u8;
+fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+match value {
+0 => Ok(Self::Type0),
+1 => Ok(Self::Type1),
+_ => Err(value),
+}
+}
+}
+impl ACTION_BUTTON_ANGLE_CHECK_TYPE {
+const SERDE_OPTIONS: &'static [(&'static str, u8)] = &[
+("Type 0", 0),
+("Type 1", 1),
+];
+}
+impl ::paramdef_codegen::serde::Serialize for ACTION_BUTTON_ANGLE_CHECK_TYPE {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+::paramdef_codegen::row_serde::serialize_enum(Self::SERDE_OPTIONS, *self as u8, serializer)
+}
+}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for ACTION_BUTTON_ANGLE_CHECK_TYPE {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+let value = ::paramdef_codegen::row_serde::deserialize_enum(deserializer, "ACTION_BUTTON_ANGLE_CHECK_TYPE", Self::SERDE_OPTIONS)?;
+Self::try_from(value).map_err(|v| {
+<D::Error as ::paramdef_codegen::serde::de::Error>::custom(format!("{} is not a value of ACTION_BUTTON_ANGLE_CHECK_TYPE", v))
+})
+}
+}
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACTION_BUTTON_TEXT_BOX_TYPE {
+/// Type 0
+Type0 = 0,
+}
+impl ::std::fmt::Display for ACTION_BUTTON_TEXT_BOX_TYPE {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+f.write_str(match self {
+Self::Type0 => "Type 0",
+})
+}
+}
+impl TryFrom<u8> for ACTION_BUTTON_TEXT_BOX_TYPE {
+type Error = u8;
+fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+match value {
+0 => Ok(Self::Type0),
+_ => Err(value),
+}
+}
+}
+impl ACTION_BUTTON_TEXT_BOX_TYPE {
+const SERDE_OPTIONS: &'static [(&'static str, u8)] = &[
+("Type 0", 0),
+];
+}
+impl ::paramdef_codegen::serde::Serialize for ACTION_BUTTON_TEXT_BOX_TYPE {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+::paramdef_codegen::row_serde::serialize_enum(Self::SERDE_OPTIONS, *self as u8, serializer)
+}
+}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for ACTION_BUTTON_TEXT_BOX_TYPE {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+let value = ::paramdef_codegen::row_serde::deserialize_enum(deserializer, "ACTION_BUTTON_TEXT_BOX_TYPE", Self::SERDE_OPTIONS)?;
+Self::try_from(value).map_err(|v| {
+<D::Error as ::paramdef_codegen::serde::de::Error>::custom(format!("{} is not a value of ACTION_BUTTON_TEXT_BOX_TYPE", v))
+})
+}
+}
+/// Row ID of the `ActionButtonParam` param.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ActionButtonParamId<T>(pub T);
+impl<T: ::paramdef_codegen::serde::Serialize> ::paramdef_codegen::serde::Serialize for ActionButtonParamId<T> {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+self.0.serialize(serializer)
+}
+}
+impl<'de, T: ::paramdef_codegen::serde::Deserialize<'de>> ::paramdef_codegen::serde::Deserialize<'de> for ActionButtonParamId<T> {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+T::deserialize(deserializer).map(Self)
+}
+}
+/// ID of a text entry of the `ActionButtonText` FMG.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ActionButtonTextFmgId<T>(pub T);
+impl<T: ::paramdef_codegen::serde::Serialize> ::paramdef_codegen::serde::Serialize for ActionButtonTextFmgId<T> {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+self.0.serialize(serializer)
+}
+}
+impl<'de, T: ::paramdef_codegen::serde::Deserialize<'de>> ::paramdef_codegen::serde::Deserialize<'de> for ActionButtonTextFmgId<T> {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+T::deserialize(deserializer).map(Self)
+}
+}
+#[repr(C)]
+pub struct ACTIONBUTTON_PARAM_ST {
+/// Range shape (cylinder, prism, capsule)
+/// ### Display Name
+/// 範囲タイプ
+/// ### Description
+/// 範囲形状(円柱、角柱、カプセル)
+/// ### Maximum
+/// 99
+pub regionType: ACTION_BUTTON_REGION_TYPE,
+/// category. The number on the left side of the name is the priority when multiple action buttons overlap (the closer it is to 0, the higher the priority is displayed).
+/// ### Display Name
+/// カテゴリ
+/// ### Description
+/// カテゴリ。名前の左側の数字は複数のアクションボタンが重なっていた場合の優先度(0に近い程優先表示)。
+/// ### Maximum
+/// 99
+pub category: ACTION_BUTTON_CATEGORY,
+/// ### Display Name
+/// パディング1
+padding1: [u8; 2],
+/// Specify the Damipoli ID that is the center of the bottom of the range. If there is no Damipoly or -1 is entered, the center coordinates will be the reference.
+/// ### Display Name
+/// ダミポリ1
+/// ### Description
+/// 範囲の底面の中心となるダミポリIDを指定する　ダミポリがない場合 or -1が入力されている場合は、中心座標が基準になる
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub dummyPoly1: ::paramdef_codegen::endian::I32Be,
+/// Used only when the range type is a capsule. Additional Damipoly (capsule) that creates a line segment with two Damipoly
+/// ### Display Name
+/// ダミポリ2
+/// ### Description
+/// 範囲タイプがカプセルの場合のみ使用　ダミポリ2つで線分を作る追加ダミポリ(カプセル)
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub dummyPoly2: ::paramdef_codegen::endian::I32Be,
+/// Radius (cylinder / capsule)
+/// ### Display Name
+/// 半径
+/// ### Description
+/// 半径(円柱・カプセル)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 1000000000
+pub radius: ::paramdef_codegen::endian::F32Be,
+/// Angle (cylinder)
+/// ### Display Name
+/// 角度
+/// ### Description
+/// 角度(円柱)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 180
+pub angle: ::paramdef_codegen::endian::I32Be,
+/// Depth (prism)
+/// ### Display Name
+/// 奥行き
+/// ### Description
+/// 奥行き(角柱)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 1000000000
+pub depth: ::paramdef_codegen::endian::F32Be,
+/// Width (prism)
+/// ### Display Name
+/// 幅
+/// ### Description
+/// 幅(角柱)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 1000000000
+pub width: ::paramdef_codegen::endian::F32Be,
+/// Height (cylinder / prism)
+/// ### Display Name
+/// 高さ
+/// ### Description
+/// 高さ(円柱・角柱)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 1000000000
+pub height: ::paramdef_codegen::endian::F32Be,
+/// How much to raise or lower the Y coordinate of the bottom (cylinder / prism)
+/// ### Display Name
+/// 底面高さオフセット
+/// ### Description
+/// 底面のY座標をどれだけ上下させるか(円柱・角柱)
+/// ### Minimum
+/// -1000000000
+/// ### Maximum
+/// 1000000000
+pub baseHeightOffset: ::paramdef_codegen::endian::F32Be,
+/// Angle difference judgment type (cylinder / prism)
+/// ### Display Name
+/// 角度差判定タイプ
+/// ### Description
+/// 角度差判定タイプ(円柱・角柱)
+/// ### Maximum
+/// 99
+pub angleCheckType: ACTION_BUTTON_ANGLE_CHECK_TYPE,
+/// ### Display Name
+/// パディング2
+padding2: [u8; 3],
+/// Allowable angle difference (cylinder / prism)
+/// ### Display Name
+/// 許容角度差
+/// ### Description
+/// 許容角度差(円柱・角柱)
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 180
+pub allowAngle: ::paramdef_codegen::endian::I32Be,
+/// If there is no Damipoli that specifies the Damipoli ID that will be the position of the action spot, or if -1 is entered, the center coordinates will be the reference.
+/// ### Display Name
+/// アクションスポットダミポリ
+/// ### Description
+/// アクションスポットの位置となるダミポリIDを指定する ダミポリがない場合 or -1が入力されている場合は、中心座標が基準となる
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub spotDummyPoly: ::paramdef_codegen::endian::I32Be,
+/// Text box type
+/// ### Display Name
+/// テキストボックスタイプ
+/// ### Description
+/// テキストボックスタイプ
+/// ### Maximum
+/// 99
+pub textBoxType: ACTION_BUTTON_TEXT_BOX_TYPE,
+/// ### Display Name
+/// パディング3
+padding3: [u8; 2],
+_bitfield1: [u8; 1],
+/// Text ID to display
+/// ### Text
+/// FMG `ActionButtonText`
+/// ### Display Name
+/// テキストID
+/// ### Description
+/// 表示するテキストID
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub textId: ActionButtonTextFmgId<::paramdef_codegen::endian::I32Be>,
+/// If this flag is ON, the action button will not appear and no judgment will be made.
+/// ### Display Name
+/// 無効フラグ
+/// ### Description
+/// このフラグがONだとアクションボタンが出ず、判定も行われない
+/// ### Maximum
+/// -294967297
+pub invalidFlag: ::paramdef_codegen::endian::U32Be,
+/// If this flag is ON, the action button will be grayed out and no judgment will be made.
+/// ### Display Name
+/// グレーアウトフラグ
+/// ### Description
+/// このフラグがONだとアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// -294967297
+pub grayoutFlag: ::paramdef_codegen::endian::U32Be,
+/// Replace with this action button ID parameter while riding (-1: No replacement)
+/// ### References
+/// - `ActionButtonParam`
+/// ### Display Name
+/// 騎乗時差し替えアクションボタンID
+/// ### Description
+/// 騎乗中はこのアクションボタンIDのパラメータに差し替える（-1：差し替え無し）
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub overrideActionButtonIdForRide: ActionButtonParamId<::paramdef_codegen::endian::I32Be>,
+/// Invalid time after execution (-infinite by value)
+/// ### Display Name
+/// 実行後無効時間
+/// ### Description
+/// 実行後無効時間(-値で無限)
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 1000000000
+pub execInvalidTime: ::paramdef_codegen::endian::F32Be,
+/// ### Display Name
+/// パディング6
+padding6: [u8; 28],
+}
+impl ACTIONBUTTON_PARAM_ST {
+/// ### Display Name
+/// パディング5
+pub fn padding5_get(&self) -> u8 {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 1u8) >> 0;
+bits as u8
+}
+/// ### Display Name
+/// パディング5
+/// ### Errors
+/// If the value does not fit in 1 bits.
+pub fn padding5_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !1u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 1 bits of padding5", value),
+));
+}
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !1u8 | bits << 0).to_be_bytes());
+Ok(())
+}
+/// ### Display Name
+/// パディング5
+pub fn padding5_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.padding5_set(f(self.padding5_get()))
+}
+/// If this item is YES, the action button will not appear when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時無効か
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForRide_get(&self) -> bool {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 2u8) >> 1;
+bits != 0
+}
+/// If this item is YES, the action button will not appear when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時無効か
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForRide_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !2u8 | bits << 1).to_be_bytes());
+}
+/// If this item is YES, the action button will not appear when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時無効か
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForRide_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isInvalidForRide_set(f(self.isInvalidForRide_get()));
+}
+/// If this item is YES, the action button will be grayed out when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時グレーアウトか
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForRide_get(&self) -> bool {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 4u8) >> 2;
+bits != 0
+}
+/// If this item is YES, the action button will be grayed out when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時グレーアウトか
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForRide_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !4u8 | bits << 2).to_be_bytes());
+}
+/// If this item is YES, the action button will be grayed out when riding and no judgment will be made.
+/// ### Display Name
+/// 騎乗時グレーアウトか
+/// ### Description
+/// この項目がYESだと騎乗時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForRide_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutForRide_set(f(self.isGrayoutForRide_get()));
+}
+/// If this item is YES, the action button will not appear when crouching, and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時無効か
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForCrouching_get(&self) -> bool {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 8u8) >> 3;
+bits != 0
+}
+/// If this item is YES, the action button will not appear when crouching, and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時無効か
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForCrouching_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !8u8 | bits << 3).to_be_bytes());
+}
+/// If this item is YES, the action button will not appear when crouching, and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時無効か
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンが出なくなり、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isInvalidForCrouching_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isInvalidForCrouching_set(f(self.isInvalidForCrouching_get()));
+}
+/// If this item is YES, the action button will be grayed out when crouching and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時グレーアウトか
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForCrouching_get(&self) -> bool {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 16u8) >> 4;
+bits != 0
+}
+/// If this item is YES, the action button will be grayed out when crouching and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時グレーアウトか
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForCrouching_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !16u8 | bits << 4).to_be_bytes());
+}
+/// If this item is YES, the action button will be grayed out when crouching and no judgment will be made.
+/// ### Display Name
+/// しゃがみ時グレーアウトか
+/// ### Description
+/// この項目がYESだとしゃがみ時にアクションボタンがグレーアウトし、判定も行われない
+/// ### Maximum
+/// 1
+pub fn isGrayoutForCrouching_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutForCrouching_set(f(self.isGrayoutForCrouching_get()));
+}
+/// ### Display Name
+/// パディング4
+pub fn padding4_get(&self) -> u8 {
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 224u8) >> 5;
+bits as u8
+}
+/// ### Display Name
+/// パディング4
+/// ### Errors
+/// If the value does not fit in 3 bits.
+pub fn padding4_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !7u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 3 bits of padding4", value),
+));
+}
+let storage = u8::from_be_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !224u8 | bits << 5).to_be_bytes());
+Ok(())
+}
+/// ### Display Name
+/// パディング4
+pub fn padding4_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.padding4_set(f(self.padding4_get()))
+}
+}
+impl Default for ACTIONBUTTON_PARAM_ST {
+fn default() -> Self {
+Self {
+regionType: ACTION_BUTTON_REGION_TYPE::Cylinder,
+category: ACTION_BUTTON_CATEGORY::Category0,
+padding1: [0; 2],
+dummyPoly1: ::paramdef_codegen::endian::I32Be::new(-1),
+dummyPoly2: ::paramdef_codegen::endian::I32Be::new(-1),
+radius: ::paramdef_codegen::endian::F32Be::new(0.0),
+angle: ::paramdef_codegen::endian::I32Be::new(180),
+depth: ::paramdef_codegen::endian::F32Be::new(0.0),
+width: ::paramdef_codegen::endian::F32Be::new(0.0),
+height: ::paramdef_codegen::endian::F32Be::new(0.0),
+baseHeightOffset: ::paramdef_codegen::endian::F32Be::new(0.0),
+angleCheckType: ACTION_BUTTON_ANGLE_CHECK_TYPE::Type0,
+padding2: [0; 3],
+allowAngle: ::paramdef_codegen::endian::I32Be::new(180),
+spotDummyPoly: ::paramdef_codegen::endian::I32Be::new(-1),
+textBoxType: ACTION_BUTTON_TEXT_BOX_TYPE::Type0,
+padding3: [0; 2],
+_bitfield1: [0; 1],
+textId: ActionButtonTextFmgId(::paramdef_codegen::endian::I32Be::new(-1)),
+invalidFlag: ::paramdef_codegen::endian::U32Be::new(0),
+grayoutFlag: ::paramdef_codegen::endian::U32Be::new(0),
+overrideActionButtonIdForRide: ActionButtonParamId(::paramdef_codegen::endian::I32Be::new(-1)),
+execInvalidTime: ::paramdef_codegen::endian::F32Be::new(0.0),
+padding6: [0; 28]
+}
+}
+}
+const _: () = assert!(::std::mem::size_of::<ACTIONBUTTON_PARAM_ST>() == 100);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, regionType) == 0);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, category) == 1);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, padding1) == 2);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, dummyPoly1) == 4);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, dummyPoly2) == 8);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, radius) == 12);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, angle) == 16);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, depth) == 20);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, width) == 24);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, height) == 28);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, baseHeightOffset) == 32);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, angleCheckType) == 36);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, padding2) == 37);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, allowAngle) == 40);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, spotDummyPoly) == 44);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, textBoxType) == 48);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, padding3) == 49);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, textId) == 52);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, invalidFlag) == 56);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, grayoutFlag) == 60);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, overrideActionButtonIdForRide) == 64);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, execInvalidTime) == 68);
+const _: () = assert!(::std::mem::offset_of!(ACTIONBUTTON_PARAM_ST, padding6) == 72);
+unsafe impl ::paramdef_codegen::param::ParamRow for ACTIONBUTTON_PARAM_ST {
+const BIG_ENDIAN: bool = true;
+
+fn invalid_field(data: &[u8]) -> ::std::option::Option<&'static str> {
+if !matches!(u8::from_ne_bytes(data[0..0 + 1].try_into().unwrap()), 0 | 1 | 2) {
+return Some("regionType");
+}
+if !matches!(u8::from_ne_bytes(data[1..1 + 1].try_into().unwrap()), 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 14) {
+return Some("category");
+}
+if !matches!(u8::from_ne_bytes(data[36..36 + 1].try_into().unwrap()), 0 | 1) {
+return Some("angleCheckType");
+}
+if !matches!(u8::from_ne_bytes(data[48..48 + 1].try_into().unwrap()), 0) {
+return Some("textBoxType");
+}
+None
+}
+}
+impl<'a> TryFrom<&::paramdef_codegen::param::Row<'a>> for &'a ACTIONBUTTON_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(row: &::paramdef_codegen::param::Row<'a>) -> ::std::io::Result<Self> {
+::paramdef_codegen::param::ParamRow::from_row(row)
+}
+}
+impl ::paramdef_codegen::reflect::ParamFields for ACTIONBUTTON_PARAM_ST {
+const FIELDS: &'static [::paramdef_codegen::reflect::FieldInfo] = &[
+::paramdef_codegen::reflect::FieldInfo {
+name: "regionType",
+display_name: Some("範囲タイプ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 0,
+bit_width: None,
+array_len: None,
+enum_name: Some("ACTION_BUTTON_REGION_TYPE"),
+minimum: None,
+maximum: Some(99.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "category",
+display_name: Some("カテゴリ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 8,
+bit_width: None,
+array_len: None,
+enum_name: Some("ACTION_BUTTON_CATEGORY"),
+minimum: None,
+maximum: Some(99.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding1",
+display_name: Some("パディング1"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 16,
+bit_width: None,
+array_len: Some(2),
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "dummyPoly1",
+display_name: Some("ダミポリ1"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 32,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(999999999.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "dummyPoly2",
+display_name: Some("ダミポリ2"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 64,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(999999999.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "radius",
+display_name: Some("半径"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 96,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "angle",
+display_name: Some("角度"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 128,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(180.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "depth",
+display_name: Some("奥行き"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 160,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "width",
+display_name: Some("幅"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 192,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "height",
+display_name: Some("高さ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 224,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "baseHeightOffset",
+display_name: Some("底面高さオフセット"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 256,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1000000000.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "angleCheckType",
+display_name: Some("角度差判定タイプ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 288,
+bit_width: None,
+array_len: None,
+enum_name: Some("ACTION_BUTTON_ANGLE_CHECK_TYPE"),
+minimum: None,
+maximum: Some(99.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding2",
+display_name: Some("パディング2"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 296,
+bit_width: None,
+array_len: Some(3),
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "allowAngle",
+display_name: Some("許容角度差"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 320,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(0.0),
+maximum: Some(180.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "spotDummyPoly",
+display_name: Some("アクションスポットダミポリ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 352,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(999999999.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "textBoxType",
+display_name: Some("テキストボックスタイプ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 384,
+bit_width: None,
+array_len: None,
+enum_name: Some("ACTION_BUTTON_TEXT_BOX_TYPE"),
+minimum: None,
+maximum: Some(99.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding3",
+display_name: Some("パディング3"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 392,
+bit_width: None,
+array_len: Some(2),
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding5",
+display_name: Some("パディング5"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 408,
+bit_width: Some(1),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "isInvalidForRide",
+display_name: Some("騎乗時無効か"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 409,
+bit_width: Some(1),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(1.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "isGrayoutForRide",
+display_name: Some("騎乗時グレーアウトか"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 410,
+bit_width: Some(1),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(1.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "isInvalidForCrouching",
+display_name: Some("しゃがみ時無効か"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 411,
+bit_width: Some(1),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(1.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "isGrayoutForCrouching",
+display_name: Some("しゃがみ時グレーアウトか"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U8,
+bit_offset: 412,
+bit_width: Some(1),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(1.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding4",
+display_name: Some("パディング4"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 413,
+bit_width: Some(3),
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "textId",
+display_name: Some("テキストID"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 416,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(999999999.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "invalidFlag",
+display_name: Some("無効フラグ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U32,
+bit_offset: 448,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(-294967297.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "grayoutFlag",
+display_name: Some("グレーアウトフラグ"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::U32,
+bit_offset: 480,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: None,
+maximum: Some(-294967297.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "overrideActionButtonIdForRide",
+display_name: Some("騎乗時差し替えアクションボタンID"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::S32,
+bit_offset: 512,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(999999999.0),
+increment: None,
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "execInvalidTime",
+display_name: Some("実行後無効時間"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::F32,
+bit_offset: 544,
+bit_width: None,
+array_len: None,
+enum_name: None,
+minimum: Some(-1.0),
+maximum: Some(1000000000.0),
+increment: Some(0.1),
+},
+::paramdef_codegen::reflect::FieldInfo {
+name: "padding6",
+display_name: Some("パディング6"),
+base_type: ::paramdef_codegen::xml_paramdef::DefBaseType::Dummy8,
+bit_offset: 576,
+bit_width: None,
+array_len: Some(28),
+enum_name: None,
+minimum: None,
+maximum: None,
+increment: None,
+}
+];
+}
+impl ::paramdef_codegen::serde::Serialize for ACTIONBUTTON_PARAM_ST {
+fn serialize<S: ::paramdef_codegen::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+use ::paramdef_codegen::serde::ser::SerializeStruct;
+let mut row = serializer.serialize_struct("ACTIONBUTTON_PARAM_ST", 23)?;
+row.serialize_field("regionType", &self.regionType)?;
+row.serialize_field("category", &self.category)?;
+row.serialize_field("dummyPoly1", &self.dummyPoly1)?;
+row.serialize_field("dummyPoly2", &self.dummyPoly2)?;
+row.serialize_field("radius", &self.radius)?;
+row.serialize_field("angle", &self.angle)?;
+row.serialize_field("depth", &self.depth)?;
+row.serialize_field("width", &self.width)?;
+row.serialize_field("height", &self.height)?;
+row.serialize_field("baseHeightOffset", &self.baseHeightOffset)?;
+row.serialize_field("angleCheckType", &self.angleCheckType)?;
+row.serialize_field("allowAngle", &self.allowAngle)?;
+row.serialize_field("spotDummyPoly", &self.spotDummyPoly)?;
+row.serialize_field("textBoxType", &self.textBoxType)?;
+row.serialize_field("isInvalidForRide", &self.isInvalidForRide_get())?;
+row.serialize_field("isGrayoutForRide", &self.isGrayoutForRide_get())?;
+row.serialize_field("isInvalidForCrouching", &self.isInvalidForCrouching_get())?;
+row.serialize_field("isGrayoutForCrouching", &self.isGrayoutForCrouching_get())?;
+row.serialize_field("textId", &self.textId)?;
+row.serialize_field("invalidFlag", &self.invalidFlag)?;
+row.serialize_field("grayoutFlag", &self.grayoutFlag)?;
+row.serialize_field("overrideActionButtonIdForRide", &self.overrideActionButtonIdForRide)?;
+row.serialize_field("execInvalidTime", &self.execInvalidTime)?;
+row.end()
+}
+}
+impl<'de> ::paramdef_codegen::serde::Deserialize<'de> for ACTIONBUTTON_PARAM_ST {
+fn deserialize<D: ::paramdef_codegen::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+let mut row = Self::default();
+::paramdef_codegen::row_serde::deserialize_into(&mut row, deserializer)?;
+Ok(row)
+}
+}
+impl ::paramdef_codegen::row_serde::DeserializeFields for ACTIONBUTTON_PARAM_ST {
+const FIELD_NAMES: &'static [&'static str] = &["regionType", "category", "dummyPoly1", "dummyPoly2", "radius", "angle", "depth", "width", "height", "baseHeightOffset", "angleCheckType", "allowAngle", "spotDummyPoly", "textBoxType", "isInvalidForRide", "isGrayoutForRide", "isInvalidForCrouching", "isGrayoutForCrouching", "textId", "invalidFlag", "grayoutFlag", "overrideActionButtonIdForRide", "execInvalidTime"];
+fn deserialize_field<'de, A: ::paramdef_codegen::serde::de::MapAccess<'de>>(&mut self, name: &str, map: &mut A) -> ::std::result::Result<bool, A::Error> {
+match name {
+"regionType" => self.regionType = map.next_value()?,
+"category" => self.category = map.next_value()?,
+"dummyPoly1" => self.dummyPoly1 = map.next_value()?,
+"dummyPoly2" => self.dummyPoly2 = map.next_value()?,
+"radius" => self.radius = map.next_value()?,
+"angle" => self.angle = map.next_value()?,
+"depth" => self.depth = map.next_value()?,
+"width" => self.width = map.next_value()?,
+"height" => self.height = map.next_value()?,
+"baseHeightOffset" => self.baseHeightOffset = map.next_value()?,
+"angleCheckType" => self.angleCheckType = map.next_value()?,
+"allowAngle" => self.allowAngle = map.next_value()?,
+"spotDummyPoly" => self.spotDummyPoly = map.next_value()?,
+"textBoxType" => self.textBoxType = map.next_value()?,
+"isInvalidForRide" => self.isInvalidForRide_set(map.next_value()?),
+"isGrayoutForRide" => self.isGrayoutForRide_set(map.next_value()?),
+"isInvalidForCrouching" => self.isInvalidForCrouching_set(map.next_value()?),
+"isGrayoutForCrouching" => self.isGrayoutForCrouching_set(map.next_value()?),
+"textId" => self.textId = map.next_value()?,
+"invalidFlag" => self.invalidFlag = map.next_value()?,
+"grayoutFlag" => self.grayoutFlag = map.next_value()?,
+"overrideActionButtonIdForRide" => self.overrideActionButtonIdForRide = map.next_value()?,
+"execInvalidTime" => self.execInvalidTime = map.next_value()?,
+_ => return Ok(false),
+}
+Ok(true)
+}
+}
+/// Row IDs and names of the `ActionButtonParam` param, from the paramdex names.
+pub mod ActionButtonParam {
+pub mod ids {
+/// Retrieve lost runes
+pub const RETRIEVE_LOST_RUNES: u32 = 1000;
+/// Touch bloodstain
+pub const TOUCH_BLOODSTAIN_1020: u32 = 1020;
+/// Ride
+pub const RIDE: u32 = 1100;
+/// Touch gold summon sign
+pub const TOUCH_GOLD_SUMMON_SIGN: u32 = 2000;
+/// Touch red summon sign
+pub const TOUCH_RED_SUMMON_SIGN: u32 = 2001;
+/// Check your summon sign
+pub const CHECK_YOUR_SUMMON_SIGN: u32 = 2010;
+/// Read message
+pub const READ_MESSAGE_3000: u32 = 3000;
+/// Check your message
+pub const CHECK_YOUR_MESSAGE: u32 = 3010;
+/// Read message
+pub const READ_MESSAGE_3020: u32 = 3020;
+/// Pick up item
+pub const PICK_UP_ITEM_4000: u32 = 4000;
+/// Pillage corpse
+pub const PILLAGE_CORPSE_4100: u32 = 4100;
+/// Pick up item
+pub const PICK_UP_ITEM_4110: u32 = 4110;
+/// Pillage remains
+pub const PILLAGE_REMAINS_4200: u32 = 4200;
+/// Pillage remains
+pub const PILLAGE_REMAINS_4201: u32 = 4201;
+/// Pillage remains
+pub const PILLAGE_REMAINS_4202: u32 = 4202;
+/// Pick up item
+pub const PICK_UP_ITEM_4250: u32 = 4250;
+/// Pick up item
+pub const PICK_UP_ITEM_4251: u32 = 4251;
+/// Pick up item
+pub const PICK_UP_ITEM_4252: u32 = 4252;
+/// Pick up item
+pub const PICK_UP_ITEM_4253: u32 = 4253;
+/// Pick up item
+pub const PICK_UP_ITEM_4260: u32 = 4260;
+/// Pick up item
+pub const PICK_UP_ITEM_4270: u32 = 4270;
+/// Pick up item
+pub const PICK_UP_ITEM_4280: u32 = 4280;
+/// Search treasure chest
+pub const SEARCH_TREASURE_CHEST: u32 = 4300;
+/// Pick up item
+pub const PICK_UP_ITEM_4350: u32 = 4350;
+/// OK
+pub const OK: u32 = 4400;
+/// Climb
+pub const CLIMB: u32 = 5000;
+/// Descend
+pub const DESCEND: u32 = 5010;
+/// Talk
+pub const TALK_6000: u32 = 6000;
+/// Talk
+pub const TALK_6001: u32 = 6001;
+/// Touch grace
+pub const TOUCH_GRACE: u32 = 6100;
+/// Rest at site of grace
+pub const REST_AT_SITE_OF_GRACE: u32 = 6101;
+/// Rest at table of lost grace
+pub const REST_AT_TABLE_OF_LOST_GRACE_6102: u32 = 6102;
+/// Rest at table of lost grace
+pub const REST_AT_TABLE_OF_LOST_GRACE_6103: u32 = 6103;
+/// Talk
+pub const TALK_6200: u32 = 6200;
+/// Talk
+pub const TALK_6210: u32 = 6210;
+/// Talk
+pub const TALK_6220: u32 = 6220;
+/// Hand over Deathroot
+pub const HAND_OVER_DEATHROOT: u32 = 6221;
+/// Talk
+pub const TALK_6222: u32 = 6222;
+/// Touch Hyetta
+pub const TOUCH_HYETTA: u32 = 6230;
+/// Examine
+pub const EXAMINE_6231: u32 = 6231;
+/// Talk
+pub const TALK_6240: u32 = 6240;
+/// Use smithing table
+pub const USE_SMITHING_TABLE: u32 = 6250;
+/// Talk
+pub const TALK_6260: u32 = 6260;
+/// Talk
+pub const TALK_6270: u32 = 6270;
+/// Talk
+pub const TALK_6271: u32 = 6271;
+/// Talk
+pub const TALK_6280: u32 = 6280;
+/// Accept primal glintstone
+pub const ACCEPT_PRIMAL_GLINTSTONE_6290: u32 = 6290;
+/// Accept primal glintstone
+pub const ACCEPT_PRIMAL_GLINTSTONE_6291: u32 = 6291;
+/// Transplant primal glintstone
+pub const TRANSPLANT_PRIMAL_GLINTSTONE: u32 = 6292;
+/// Talk
+pub const TALK_6300: u32 = 6300;
+/// Touch Fractured Marika
+pub const TOUCH_FRACTURED_MARIKA: u32 = 6310;
+/// Examine
+pub const EXAMINE_6320: u32 = 6320;
+/// Touch the Two Fingers
+pub const TOUCH_THE_TWO_FINGERS: u32 = 6321;
+/// Be held
+pub const BE_HELD: u32 = 6330;
+/// Touch Fia
+pub const TOUCH_FIA: u32 = 6331;
+/// Talk
+pub const TALK_6332: u32 = 6332;
+/// Summon Latenna
+pub const SUMMON_LATENNA: u32 = 6340;
+/// Examine
+pub const EXAMINE_6350: u32 = 6350;
+/// Talk
+pub const TALK_6360: u32 = 6360;
+/// Pick up item
+pub const PICK_UP_ITEM_6361: u32 = 6361;
+/// Atone
+pub const ATONE: u32 = 6370;
+/// Use dressing table
+pub const USE_DRESSING_TABLE: u32 = 6380;
+/// Speak the truth about Radagon
+pub const SPEAK_THE_TRUTH_ABOUT_RADAGON: u32 = 6390;
+/// Talk
+pub const TALK_6400: u32 = 6400;
+/// Administer draught
+pub const ADMINISTER_DRAUGHT_6410: u32 = 6410;
+/// Administer draught
+pub const ADMINISTER_DRAUGHT_6411: u32 = 6411;
+/// Administer draught
+pub const ADMINISTER_DRAUGHT_6412: u32 = 6412;
+/// Where did the body go?
+pub const WHERE_DID_THE_BODY_GO: u32 = 6413;
+/// Talk
+pub const TALK_6414: u32 = 6414;
+/// Examine
+pub const EXAMINE_6420: u32 = 6420;
+/// Examine remains
+pub const EXAMINE_REMAINS: u32 = 6430;
+/// Receive mending rune
+pub const RECEIVE_MENDING_RUNE_6440: u32 = 6440;
+/// Receive mending rune
+pub const RECEIVE_MENDING_RUNE_6450: u32 = 6450;
+/// Receive mending rune
+pub const RECEIVE_MENDING_RUNE_6460: u32 = 6460;
+/// Offer finger
+pub const OFFER_FINGER: u32 = 6470;
+/// Dye cloth with maiden's blood
+pub const DYE_CLOTH_WITH_MAIDENS_BLOOD_6471: u32 = 6471;
+/// Dye cloth with maiden's blood
+pub const DYE_CLOTH_WITH_MAIDENS_BLOOD_6472: u32 = 6472;
+/// Talk
+pub const TALK_6480: u32 = 6480;
+/// Talk
+pub const TALK_6490: u32 = 6490;
+/// Talk
+pub const TALK_6500: u32 = 6500;
+/// Touch bloodstain
+pub const TOUCH_BLOODSTAIN_6511: u32 = 6511;
+/// Return Unalloyed Gold Needle
+pub const RETURN_UNALLOYED_GOLD_NEEDLE: u32 = 6519;
+/// Talk
+pub const TALK_6540: u32 = 6540;
+/// Talk
+pub const TALK_6570: u32 = 6570;
+/// Examine altar
+pub const EXAMINE_ALTAR: u32 = 6580;
+/// Examine grave
+pub const EXAMINE_GRAVE: u32 = 6590;
+/// Talk
+pub const TALK_6600: u32 = 6600;
+/// Examine
+pub const EXAMINE_6700: u32 = 6700;
+/// Open
+pub const OPEN_7100: u32 = 7100;
+/// Open
+pub const OPEN_7101: u32 = 7101;
+/// Open
+pub const OPEN_7102: u32 = 7102;
+/// Close
+pub const CLOSE_7110: u32 = 7110;
+/// Open
+pub const OPEN_7200: u32 = 7200;
+/// Open
+pub const OPEN_7201: u32 = 7201;
+/// Open
+pub const OPEN_7202: u32 = 7202;
+/// Close
+pub const CLOSE_7210: u32 = 7210;
+/// Open
+pub const OPEN_7220: u32 = 7220;
+/// Open
+pub const OPEN_7300: u32 = 7300;
+/// Open
+pub const OPEN_7301: u32 = 7301;
+/// Open
+pub const OPEN_7320: u32 = 7320;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7800: u32 = 7800;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7810: u32 = 7810;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7811: u32 = 7811;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7812: u32 = 7812;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7813: u32 = 7813;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7814: u32 = 7814;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7815: u32 = 7815;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7816: u32 = 7816;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7817: u32 = 7817;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7818: u32 = 7818;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7819: u32 = 7819;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7820: u32 = 7820;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7821: u32 = 7821;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7822: u32 = 7822;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7823: u32 = 7823;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7824: u32 = 7824;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7825: u32 = 7825;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7826: u32 = 7826;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7827: u32 = 7827;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7828: u32 = 7828;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7850: u32 = 7850;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7860: u32 = 7860;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7861: u32 = 7861;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7862: u32 = 7862;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7863: u32 = 7863;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7864: u32 = 7864;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7865: u32 = 7865;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7866: u32 = 7866;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7867: u32 = 7867;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7868: u32 = 7868;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7869: u32 = 7869;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7870: u32 = 7870;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7871: u32 = 7871;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7872: u32 = 7872;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7873: u32 = 7873;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7874: u32 = 7874;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7875: u32 = 7875;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7876: u32 = 7876;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7877: u32 = 7877;
+/// Acquire materials
+pub const ACQUIRE_MATERIALS_7878: u32 = 7878;
+/// Open
+pub const OPEN_8000: u32 = 8000;
+/// Open
+pub const OPEN_8100: u32 = 8100;
+/// Pull lever
+pub const PULL_LEVER_8200: u32 = 8200;
+/// Pull lever
+pub const PULL_LEVER_8300: u32 = 8300;
+/// Pull lever
+pub const PULL_LEVER_8301: u32 = 8301;
+/// Pull lever
+pub const PULL_LEVER_8302: u32 = 8302;
+/// Pull lever
+pub const PULL_LEVER_8310: u32 = 8310;
+/// Pull lever
+pub const PULL_LEVER_8320: u32 = 8320;
+/// Pull lever
+pub const PULL_LEVER_8400: u32 = 8400;
+/// Pull lever
+pub const PULL_LEVER_8401: u32 = 8401;
+/// Pull lever
+pub const PULL_LEVER_8402: u32 = 8402;
+/// Push lever
+pub const PUSH_LEVER: u32 = 8403;
+/// Pull lever
+pub const PULL_LEVER_8420: u32 = 8420;
+/// Pull lever
+pub const PULL_LEVER_8501: u32 = 8501;
+/// Open
+pub const OPEN_8920: u32 = 8920;
+/// Examine
+pub const EXAMINE_9000: u32 = 9000;
+/// Invade White Mask Varré's world
+pub const INVADE_WHITE_MASK_VARRS_WORLD: u32 = 9051;
+/// Invade Old Knight Istvan's world
+pub const INVADE_OLD_KNIGHT_ISTVANS_WORLD: u32 = 9052;
+/// Invade Rileigh the Idle's world
+pub const INVADE_RILEIGH_THE_IDLES_WORLD: u32 = 9053;
+/// Invade Juno Hoslow Knight of Blood's world
+pub const INVADE_JUNO_HOSLOW_KNIGHT_OF_BLOODS_WORLD: u32 = 9054;
+/// Invade Vargram the Raging Wolf's world
+pub const INVADE_VARGRAM_THE_RAGING_WOLFS_WORLD: u32 = 9055;
+/// Invade Great Horned Tragoth's world
+pub const INVADE_GREAT_HORNED_TRAGOTHS_WORLD: u32 = 9056;
+/// Be summoned to assist Yura Hunter of Bloody Fingers
+pub const BE_SUMMONED_TO_ASSIST_YURA_HUNTER_OF_BLOODY_FINGERS: u32 = 9057;
+/// Be summoned to assist Sorceress Sellen
+pub const BE_SUMMONED_TO_ASSIST_SORCERESS_SELLEN: u32 = 9058;
+/// Be summoned to challenge Sorceress Sellen
+pub const BE_SUMMONED_TO_CHALLENGE_SORCERESS_SELLEN: u32 = 9059;
+/// Be summoned to assist Millicent
+pub const BE_SUMMONED_TO_ASSIST_MILLICENT: u32 = 9060;
+/// Be summoned to challenge Millicent
+pub const BE_SUMMONED_TO_CHALLENGE_MILLICENT: u32 = 9061;
+/// Touch cooperative sign
+pub const TOUCH_COOPERATIVE_SIGN: u32 = 9070;
+/// Touch invasion sign
+pub const TOUCH_INVASION_SIGN: u32 = 9071;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9080: u32 = 9080;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9081: u32 = 9081;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9082: u32 = 9082;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9083: u32 = 9083;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9084: u32 = 9084;
+/// Restore the power of the Great Rune
+pub const RESTORE_THE_POWER_OF_THE_GREAT_RUNE_9085: u32 = 9085;
+/// Touch
+pub const TOUCH: u32 = 9100;
+/// Examine
+pub const EXAMINE_9110: u32 = 9110;
+/// Open
+pub const OPEN_9120: u32 = 9120;
+/// Examine
+pub const EXAMINE_9140: u32 = 9140;
+/// Examine
+pub const EXAMINE_9190: u32 = 9190;
+/// Kick ladder
+pub const KICK_LADDER: u32 = 9200;
+/// Examine
+pub const EXAMINE_9210: u32 = 9210;
+/// Examine
+pub const EXAMINE_9220: u32 = 9220;
+/// Examine
+pub const EXAMINE_9230: u32 = 9230;
+/// Stargaze
+pub const STARGAZE: u32 = 9240;
+/// Examine
+pub const EXAMINE_9260: u32 = 9260;
+/// Use Birdseye Telescope
+pub const USE_BIRDSEYE_TELESCOPE: u32 = 9270;
+/// Examine
+pub const EXAMINE_9280: u32 = 9280;
+/// Return to entrance
+pub const RETURN_TO_ENTRANCE: u32 = 9290;
+/// Examine
+pub const EXAMINE_9300: u32 = 9300;
+/// Examine
+pub const EXAMINE_9310: u32 = 9310;
+/// Examine
+pub const EXAMINE_9320: u32 = 9320;
+/// Examine
+pub const EXAMINE_9330: u32 = 9330;
+/// Touch wall of thorns
+pub const TOUCH_WALL_OF_THORNS: u32 = 9500;
+/// Touch golden light
+pub const TOUCH_GOLDEN_LIGHT: u32 = 9501;
+/// Examine seal
+pub const EXAMINE_SEAL_9503: u32 = 9503;
+/// Examine
+pub const EXAMINE_9504: u32 = 9504;
+/// Examine seal
+pub const EXAMINE_SEAL_9505: u32 = 9505;
+/// Examine seal
+pub const EXAMINE_SEAL_9506: u32 = 9506;
+/// Examine
+pub const EXAMINE_9519: u32 = 9519;
+/// Light flame
+pub const LIGHT_FLAME_9520: u32 = 9520;
+/// Touch phantom
+pub const TOUCH_PHANTOM: u32 = 9521;
+/// Examine seal
+pub const EXAMINE_SEAL_9522: u32 = 9522;
+/// Examine
+pub const EXAMINE_9523: u32 = 9523;
+/// Light flame
+pub const LIGHT_FLAME_9524: u32 = 9524;
+/// Touch horned remains
+pub const TOUCH_HORNED_REMAINS: u32 = 9525;
+/// Go back
+pub const GO_BACK: u32 = 9526;
+/// Examine
+pub const EXAMINE_9527: u32 = 9527;
+/// Light flame
+pub const LIGHT_FLAME_9528: u32 = 9528;
+/// Examine seal
+pub const EXAMINE_SEAL_9529: u32 = 9529;
+/// Examine seal
+pub const EXAMINE_SEAL_9530: u32 = 9530;
+/// Examine
+pub const EXAMINE_9531: u32 = 9531;
+/// Pillage corpse
+pub const PILLAGE_CORPSE_9532: u32 = 9532;
+/// Examine
+pub const EXAMINE_9533: u32 = 9533;
+/// Summon Castellan Jerren
+pub const SUMMON_CASTELLAN_JERREN: u32 = 9540;
+/// Summon Blaidd the Half-Wolf
+pub const SUMMON_BLAIDD_THE_HALF_WOLF: u32 = 9541;
+/// Summon Alexander Warrior Jar
+pub const SUMMON_ALEXANDER_WARRIOR_JAR: u32 = 9542;
+/// Summon Great Horned Tragoth
+pub const SUMMON_GREAT_HORNED_TRAGOTH: u32 = 9543;
+/// Summon Okina
+pub const SUMMON_OKINA: u32 = 9544;
+/// Summon Finger Maiden Therolina
+pub const SUMMON_FINGER_MAIDEN_THEROLINA: u32 = 9545;
+/// Summon Lionel the Lionhearted
+pub const SUMMON_LIONEL_THE_LIONHEARTED: u32 = 9546;
+/// Summon Patches
+pub const SUMMON_PATCHES: u32 = 9547;
+/// Examine
+pub const EXAMINE_9550: u32 = 9550;
+/// Become the Elden Lord
+pub const BECOME_THE_ELDEN_LORD: u32 = 9600;
+/// Summon Ranni
+pub const SUMMON_RANNI: u32 = 9610;
+/// Become the Lord of Frenzied Flame
+pub const BECOME_THE_LORD_OF_FRENZIED_FLAME: u32 = 9620;
+/// Wear ring
+pub const WEAR_RING: u32 = 9630;
+/// Open door
+pub const OPEN_DOOR_9640: u32 = 9640;
+/// Hoist medallion
+pub const HOIST_MEDALLION: u32 = 9700;
+/// Hoist secret medallion
+pub const HOIST_SECRET_MEDALLION: u32 = 9701;
+/// Rest in coffin
+pub const REST_IN_COFFIN_9710: u32 = 9710;
+/// Rest in coffin
+pub const REST_IN_COFFIN_9711: u32 = 9711;
+/// Examine pedestal
+pub const EXAMINE_PEDESTAL: u32 = 9720;
+/// Remove inverted statue
+pub const REMOVE_INVERTED_STATUE: u32 = 9721;
+/// Open door
+pub const OPEN_DOOR_9730: u32 = 9730;
+/// Lie down
+pub const LIE_DOWN: u32 = 9740;
+/// Examine
+pub const EXAMINE_9980: u32 = 9980;
+/// Examine
+pub const EXAMINE_9999: u32 = 9999;
+/// Traverse the mist
+pub const TRAVERSE_THE_MIST_10000: u32 = 10000;
+/// Traverse the mist
+pub const TRAVERSE_THE_MIST_10001: u32 = 10001;
+/// Traverse the mist
+pub const TRAVERSE_THE_MIST_10010: u32 = 10010;
+}
+/// Row names by ID.
+pub static NAMES: ::paramdef_codegen::phf::Map<u32, &'static str> = ::paramdef_codegen::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 194),
+        (0, 54),
+        (0, 1),
+        (0, 38),
+        (0, 67),
+        (0, 84),
+        (0, 6),
+        (0, 90),
+        (0, 36),
+        (0, 141),
+        (1, 78),
+        (0, 149),
+        (0, 124),
+        (1, 0),
+        (0, 3),
+        (5, 182),
+        (0, 38),
+        (0, 38),
+        (0, 4),
+        (0, 186),
+        (0, 11),
+        (2, 232),
+        (0, 81),
+        (1, 139),
+        (1, 4),
+        (1, 80),
+        (11, 119),
+        (0, 19),
+        (0, 198),
+        (1, 35),
+        (1, 2),
+        (0, 22),
+        (1, 2),
+        (0, 118),
+        (12, 35),
+        (75, 191),
+        (0, 14),
+        (1, 74),
+        (3, 18),
+        (4, 37),
+        (0, 0),
+        (68, 61),
+        (0, 0),
+        (0, 11),
+        (8, 96),
+        (0, 12),
+        (2, 109),
+        (3, 54),
+        (0, 74),
+    ],
+    entries: &[
+        (9620, "Become the Lord of Frenzied Flame"),
+        (9740, "Lie down"),
+        (3010, "Check your message"),
+        (8420, "Pull lever"),
+        (4000, "Pick up item"),
+        (7812, "Acquire materials"),
+        (4252, "Pick up item"),
+        (7811, "Acquire materials"),
+        (4201, "Pillage remains"),
+        (9060, "Be summoned to assist Millicent"),
+        (9058, "Be summoned to assist Sorceress Sellen"),
+        (4251, "Pick up item"),
+        (7877, "Acquire materials"),
+        (9546, "Summon Lionel the Lionhearted"),
+        (6100, "Touch grace"),
+        (10010, "Traverse the mist"),
+        (7813, "Acquire materials"),
+        (6570, "Talk"),
+        (9059, "Be summoned to challenge Sorceress Sellen"),
+        (4400, "OK"),
+        (6231, "Examine"),
+        (6280, "Talk"),
+        (7864, "Acquire materials"),
+        (9721, "Remove inverted statue"),
+        (9053, "Invade Rileigh the Idle's world"),
+        (9532, "Pillage corpse"),
+        (7220, "Open"),
+        (8300, "Pull lever"),
+        (6350, "Examine"),
+        (9711, "Rest in coffin"),
+        (2010, "Check your summon sign"),
+        (4202, "Pillage remains"),
+        (8400, "Pull lever"),
+        (9110, "Examine"),
+        (7861, "Acquire materials"),
+        (9527, "Examine"),
+        (6292, "Transplant primal glintstone"),
+        (7870, "Acquire materials"),
+        (6291, "Accept primal glintstone"),
+        (8401, "Pull lever"),
+        (7823, "Acquire materials"),
+        (9057, "Be summoned to assist Yura Hunter of Bloody Fingers"),
+        (4300, "Search treasure chest"),
+        (9200, "Kick ladder"),
+        (7320, "Open"),
+        (9701, "Hoist secret medallion"),
+        (6700, "Examine"),
+        (8402, "Pull lever"),
+        (7868, "Acquire materials"),
+        (9300, "Examine"),
+        (9310, "Examine"),
+        (6001, "Talk"),
+        (9710, "Rest in coffin"),
+        (8302, "Pull lever"),
+        (6511, "Touch bloodstain"),
+        (7876, "Acquire materials"),
+        (7815, "Acquire materials"),
+        (6290, "Accept primal glintstone"),
+        (6440, "Receive mending rune"),
+        (6410, "Administer draught"),
+        (6390, "Speak the truth about Radagon"),
+        (7878, "Acquire materials"),
+        (6000, "Talk"),
+        (6221, "Hand over Deathroot"),
+        (9330, "Examine"),
+        (8320, "Pull lever"),
+        (9080, "Restore the power of the Great Rune"),
+        (9530, "Examine seal"),
+        (7825, "Acquire materials"),
+        (6220, "Talk"),
+        (9640, "Open door"),
+        (8000, "Open"),
+        (6260, "Talk"),
+        (7301, "Open"),
+        (10001, "Traverse the mist"),
+        (9525, "Touch horned remains"),
+        (6519, "Return Unalloyed Gold Needle"),
+        (8920, "Open"),
+        (9500, "Touch wall of thorns"),
+        (9505, "Examine seal"),
+        (9120, "Open"),
+        (7869, "Acquire materials"),
+        (7874, "Acquire materials"),
+        (6490, "Talk"),
+        (7814, "Acquire materials"),
+        (9100, "Touch"),
+        (4110, "Pick up item"),
+        (6230, "Touch Hyetta"),
+        (9210, "Examine"),
+        (6600, "Talk"),
+        (6320, "Examine"),
+        (6270, "Talk"),
+        (4100, "Pillage corpse"),
+        (9230, "Examine"),
+        (9523, "Examine"),
+        (7102, "Open"),
+        (4253, "Pick up item"),
+        (9052, "Invade Old Knight Istvan's world"),
+        (6240, "Talk"),
+        (9531, "Examine"),
+        (9504, "Examine"),
+        (9320, "Examine"),
+        (9630, "Wear ring"),
+        (6360, "Talk"),
+        (9061, "Be summoned to challenge Millicent"),
+        (9544, "Summon Okina"),
+        (5000, "Climb"),
+        (9720, "Examine pedestal"),
+        (9547, "Summon Patches"),
+        (6102, "Rest at table of lost grace"),
+        (7860, "Acquire materials"),
+        (7862, "Acquire materials"),
+        (7850, "Acquire materials"),
+        (7863, "Acquire materials"),
+        (7210, "Close"),
+        (9730, "Open door"),
+        (8100, "Open"),
+        (6340, "Summon Latenna"),
+        (9543, "Summon Great Horned Tragoth"),
+        (6361, "Pick up item"),
+        (7826, "Acquire materials"),
+        (7822, "Acquire materials"),
+        (9529, "Examine seal"),
+        (7110, "Close"),
+        (7866, "Acquire materials"),
+        (6413, "Where did the body go?"),
+        (6472, "Dye cloth with maiden's blood"),
+        (7820, "Acquire materials"),
+        (6580, "Examine altar"),
+        (9260, "Examine"),
+        (9082, "Restore the power of the Great Rune"),
+        (4250, "Pick up item"),
+        (6430, "Examine remains"),
+        (9541, "Summon Blaidd the Half-Wolf"),
+        (9220, "Examine"),
+        (4280, "Pick up item"),
+        (9140, "Examine"),
+        (7300, "Open"),
+        (9051, "Invade White Mask Varré's world"),
+        (1000, "Retrieve lost runes"),
+        (9521, "Touch phantom"),
+        (8200, "Pull lever"),
+        (6101, "Rest at site of grace"),
+        (6471, "Dye cloth with maiden's blood"),
+        (8501, "Pull lever"),
+        (6480, "Talk"),
+        (7819, "Acquire materials"),
+        (6370, "Atone"),
+        (7100, "Open"),
+        (6210, "Talk"),
+        (7867, "Acquire materials"),
+        (7865, "Acquire materials"),
+        (7827, "Acquire materials"),
+        (7202, "Open"),
+        (4270, "Pick up item"),
+        (9533, "Examine"),
+        (3000, "Read message"),
+        (8301, "Pull lever"),
+        (6460, "Receive mending rune"),
+        (6380, "Use dressing table"),
+        (10000, "Traverse the mist"),
+        (7872, "Acquire materials"),
+        (8403, "Push lever"),
+        (9084, "Restore the power of the Great Rune"),
+        (9280, "Examine"),
+        (9056, "Invade Great Horned Tragoth's world"),
+        (9085, "Restore the power of the Great Rune"),
+        (9070, "Touch cooperative sign"),
+        (9240, "Stargaze"),
+        (6321, "Touch the Two Fingers"),
+        (6400, "Talk"),
+        (9700, "Hoist medallion"),
+        (2000, "Touch gold summon sign"),
+        (9290, "Return to entrance"),
+        (9545, "Summon Finger Maiden Therolina"),
+        (9000, "Examine"),
+        (7871, "Acquire materials"),
+        (6310, "Touch Fractured Marika"),
+        (9600, "Become the Elden Lord"),
+        (8310, "Pull lever"),
+        (9083, "Restore the power of the Great Rune"),
+        (4200, "Pillage remains"),
+        (7828, "Acquire materials"),
+        (6412, "Administer draught"),
+        (7873, "Acquire materials"),
+        (9519, "Examine"),
+        (1020, "Touch bloodstain"),
+        (6470, "Offer finger"),
+        (9542, "Summon Alexander Warrior Jar"),
+        (3020, "Read message"),
+        (6222, "Talk"),
+        (9071, "Touch invasion sign"),
+        (6330, "Be held"),
+        (7800, "Acquire materials"),
+        (9190, "Examine"),
+        (6540, "Talk"),
+        (9081, "Restore the power of the Great Rune"),
+        (2001, "Touch red summon sign"),
+        (7818, "Acquire materials"),
+        (7101, "Open"),
+        (6200, "Talk"),
+        (6103, "Rest at table of lost grace"),
+        (6332, "Talk"),
+        (6250, "Use smithing table"),
+        (7200, "Open"),
+        (5010, "Descend"),
+        (9610, "Summon Ranni"),
+        (6590, "Examine grave"),
+        (9526, "Go back"),
+        (7824, "Acquire materials"),
+        (9054, "Invade Juno Hoslow Knight of Blood's world"),
+        (1100, "Ride"),
+        (9506, "Examine seal"),
+        (6300, "Talk"),
+        (9999, "Examine"),
+        (9524, "Light flame"),
+        (9528, "Light flame"),
+        (7816, "Acquire materials"),
+        (9522, "Examine seal"),
+        (6420, "Examine"),
+        (4350, "Pick up item"),
+        (6414, "Talk"),
+        (6331, "Touch Fia"),
+        (9980, "Examine"),
+        (4260, "Pick up item"),
+        (6450, "Receive mending rune"),
+        (7817, "Acquire materials"),
+        (9270, "Use Birdseye Telescope"),
+        (9503, "Examine seal"),
+        (9501, "Touch golden light"),
+        (9550, "Examine"),
+        (6500, "Talk"),
+        (7201, "Open"),
+        (9520, "Light flame"),
+        (9055, "Invade Vargram the Raging Wolf's world"),
+        (6411, "Administer draught"),
+        (9540, "Summon Castellan Jerren"),
+        (6271, "Talk"),
+        (7875, "Acquire materials"),
+        (7810, "Acquire materials"),
+        (7821, "Acquire materials"),
+    ],
+};
+/// Row IDs by name. If several rows have the same name, this is the lowest of their IDs.
+pub static IDS: ::paramdef_codegen::phf::Map<&'static str, u32> = ::paramdef_codegen::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 2),
+        (3, 27),
+        (3, 32),
+        (0, 4),
+        (0, 20),
+        (0, 53),
+        (0, 46),
+        (33, 12),
+        (4, 63),
+        (1, 0),
+        (51, 6),
+        (0, 0),
+        (15, 14),
+        (1, 27),
+        (19, 69),
+        (4, 63),
+        (28, 77),
+        (0, 5),
+        (0, 85),
+    ],
+    entries: &[
+        ("Touch the Two Fingers", 6321),
+        ("Be summoned to assist Millicent", 9060),
+        ("Pick up item", 4000),
+        ("Read message", 3000),
+        ("Wear ring", 9630),
+        ("Pillage remains", 4200),
+        ("Invade Great Horned Tragoth's world", 9056),
+        ("Summon Finger Maiden Therolina", 9545),
+        ("Kick ladder", 9200),
+        ("Summon Ranni", 9610),
+        ("Examine altar", 6580),
+        ("Rest at site of grace", 6101),
+        ("Push lever", 8403),
+        ("Become the Elden Lord", 9600),
+        ("Where did the body go?", 6413),
+        ("Retrieve lost runes", 1000),
+        ("Ride", 1100),
+        ("Summon Great Horned Tragoth", 9543),
+        ("Restore the power of the Great Rune", 9080),
+        ("Touch wall of thorns", 9500),
+        ("Become the Lord of Frenzied Flame", 9620),
+        ("Touch Hyetta", 6230),
+        ("Pull lever", 8200),
+        ("Atone", 6370),
+        ("Return to entrance", 9290),
+        ("Rest at table of lost grace", 6102),
+        ("Hand over Deathroot", 6221),
+        ("Offer finger", 6470),
+        ("Summon Latenna", 6340),
+        ("Touch", 9100),
+        ("Check your summon sign", 2010),
+        ("Use dressing table", 6380),
+        ("OK", 4400),
+        ("Touch phantom", 9521),
+        ("Touch golden light", 9501),
+        ("Traverse the mist", 10000),
+        ("Invade Old Knight Istvan's world", 9052),
+        ("Receive mending rune", 6440),
+        ("Accept primal glintstone", 6290),
+        ("Open door", 9640),
+        ("Touch red summon sign", 2001),
+        ("Examine seal", 9503),
+        ("Light flame", 9520),
+        ("Use smithing table", 6250),
+        ("Invade Vargram the Raging Wolf's world", 9055),
+        ("Talk", 6000),
+        ("Touch cooperative sign", 9070),
+        ("Examine grave", 6590),
+        ("Climb", 5000),
+        ("Invade Rileigh the Idle's world", 9053),
+        ("Descend", 5010),
+        ("Transplant primal glintstone", 6292),
+        ("Touch horned remains", 9525),
+        ("Touch Fractured Marika", 6310),
+        ("Touch gold summon sign", 2000),
+        ("Rest in coffin", 9710),
+        ("Be summoned to assist Sorceress Sellen", 9058),
+        ("Hoist medallion", 9700),
+        ("Go back", 9526),
+        ("Touch Fia", 6331),
+        ("Lie down", 9740),
+        ("Stargaze", 9240),
+        ("Acquire materials", 7800),
+        ("Touch bloodstain", 1020),
+        ("Invade White Mask Varré's world", 9051),
+        ("Be summoned to assist Yura Hunter of Bloody Fingers", 9057),
+        ("Summon Blaidd the Half-Wolf", 9541),
+        ("Be held", 6330),
+        ("Be summoned to challenge Sorceress Sellen", 9059),
+        ("Remove inverted statue", 9721),
+        ("Summon Okina", 9544),
+        ("Summon Castellan Jerren", 9540),
+        ("Examine pedestal", 9720),
+        ("Touch grace", 6100),
+        ("Open", 7100),
+        ("Hoist secret medallion", 9701),
+        ("Pillage corpse", 4100),
+        ("Use Birdseye Telescope", 9270),
+        ("Administer draught", 6410),
+        ("Speak the truth about Radagon", 6390),
+        ("Dye cloth with maiden's blood", 6471),
+        ("Invade Juno Hoslow Knight of Blood's world", 9054),
+        ("Touch invasion sign", 9071),
+        ("Close", 7110),
+        ("Return Unalloyed Gold Needle", 6519),
+        ("Examine", 6231),
+        ("Summon Lionel the Lionhearted", 9546),
+        ("Summon Patches", 9547),
+        ("Check your message", 3010),
+        ("Search treasure chest", 4300),
+        ("Be summoned to challenge Millicent", 9061),
+        ("Summon Alexander Warrior Jar", 9542),
+        ("Examine remains", 6430),
+    ],
+};
+}
@@ -0,0 +1,1810 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SWORD_ARTS_REF_STATUS_TYPE(pub i8);
+#[allow(non_upper_case_globals)]
+impl SWORD_ARTS_REF_STATUS_TYPE {
+/// Type 1
+pub const Type1: Self = Self(1);
+}
+impl ::std::fmt::Display for SWORD_ARTS_REF_STATUS_TYPE {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+let names: &[(Self, &str)] = &[
+(Self::Type1, "Type 1"),
+];
+match names.iter().find(|(value, _)| value == self) {
+Some((_, name)) => f.write_str(name),
+None => ::std::fmt::Display::fmt(&self.0, f),
+}
+}
+}
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SWORD_ARTS_SHIELD_ICON_TYPE(pub i8);
+#[allow(non_upper_case_globals)]
+impl SWORD_ARTS_SHIELD_ICON_TYPE {
+/// Type 1
+pub const Type1: Self = Self(0);
+/// Type 3: Shield Bash
+pub const Type3ShieldBash: Self = Self(3);
+}
+impl ::std::fmt::Display for SWORD_ARTS_SHIELD_ICON_TYPE {
+fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+let names: &[(Self, &str)] = &[
+(Self::Type1, "Type 1"),
+(Self::Type3ShieldBash, "Type 3: Shield Bash"),
+];
+match names.iter().find(|(value, _)| value == self) {
+Some((_, name)) => f.write_str(name),
+None => ::std::fmt::Display::fmt(&self.0, f),
+}
+}
+}
+/// ID of a text entry of the `TitleSwordArts` FMG.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TitleSwordArtsFmgId<T>(pub T);
+/// Base layout of the param.
+pub mod v0 {
+#[allow(unused_imports)]
+use super::*;
+/// Defines Ashes of War, including unique AoW. Determines AoW animation.\nBehaviour data remains in BehaviourParam. Unique AoW data is with the weapon and not with other AoW data.
+#[repr(C)]
+pub struct SWORD_ARTS_PARAM_ST {
+_bitfield1: [u8; 1],
+/// ### Display Name
+/// パッケージ出力用リザーブ2
+/// ### Description
+/// パッケージ出力用リザーブ2
+disableParamReserve2: [u8; 3],
+/// Offset for TAE animation. 0 is a600. To pass to the behavior script to determine which swashbuckler
+/// ### Display Name
+/// 剣戟ID
+/// ### Description
+/// ビヘイビアスクリプトに渡してどの剣戟か判定するためのもの
+pub swordArtsType: u8,
+/// Which cancellation timing do you see? 0: Normal (left hand attack) / 1: Early / 2: Slow
+/// ### Display Name
+/// アーツ速度
+/// ### Description
+/// どのキャンセルタイミングを見るか。0：通常（左手攻撃）／1：早い／2：遅い
+/// ### Maximum
+/// 2
+pub artsSpeedType: u8,
+/// Which system of arts points to refer to
+/// ### Display Name
+/// 関連ステータス
+/// ### Description
+/// どの系統のアーツポイントを参照するか
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 128
+pub refStatus: SWORD_ARTS_REF_STATUS_TYPE,
+_bitfield2: [u8; 1],
+/// Points to spend when putting out arts by L1
+/// ### Display Name
+/// 消費ポイント L1
+/// ### Description
+/// L1によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_L1: i8,
+/// Points to spend when putting out arts by L2
+/// ### Display Name
+/// 消費ポイント L2
+/// ### Description
+/// L2によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_L2: i8,
+/// Points to spend when putting out arts by R1
+/// ### Display Name
+/// 消費ポイント R1
+/// ### Description
+/// R1によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_R1: i8,
+/// Points to spend when putting out arts by R2
+/// ### Display Name
+/// 消費ポイント R2
+/// ### Description
+/// R2によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_R2: i8,
+/// Text ID for arts description
+/// ### Text
+/// FMG `TitleSwordArts`
+/// ### Display Name
+/// テキストID
+/// ### Description
+/// アーツ説明用のテキストID
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 999999999
+pub textId: TitleSwordArtsFmgId<i32>,
+/// MP consumed when issuing arts by L1
+/// ### Display Name
+/// 消費MP L1
+/// ### Description
+/// L1によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_L1: i16,
+/// MP consumed when issuing arts by L2
+/// ### Display Name
+/// 消費MP L2
+/// ### Description
+/// L2によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_L2: i16,
+/// MP consumed when issuing arts by R1
+/// ### Display Name
+/// 消費MP R1
+/// ### Description
+/// R1によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_R1: i16,
+/// MP consumed when issuing arts by R2
+/// ### Display Name
+/// 消費MP R2
+/// ### Description
+/// R2によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_R2: i16,
+/// If you do not overwrite it, the icon will be displayed based on the swashbuckler ID of the weapon para.
+/// ### Display Name
+/// 盾種別アイコン（上書き）
+/// ### Description
+/// 上書きしない場合は、武器パラの剣戟IDを元にアイコン表示されます。
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 128
+pub shieldIconType: SWORD_ARTS_SHIELD_ICON_TYPE,
+pad: [u8; 1],
+/// ID of the icon displayed in FE etc.
+/// ### Display Name
+/// アイコンID
+/// ### Description
+/// FEなどで表示するアイコンのID
+pub iconId: u16,
+/// AI usage judgment ID
+/// ### Display Name
+/// AI使用判断ID
+/// ### Description
+/// AI使用判断ID
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub aiUsageId: i32,
+}
+impl SWORD_ARTS_PARAM_ST {
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 1u8) >> 0;
+bits != 0
+}
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !1u8 | bits << 0).to_le_bytes());
+}
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_update(&mut self, f: impl Fn(bool) -> bool) {
+self.disableParam_NT_set(f(self.disableParam_NT_get()));
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+pub fn disableParamReserve1_get(&self) -> u8 {
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 254u8) >> 1;
+bits as u8
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+/// ### Errors
+/// If the value does not fit in 7 bits.
+pub fn disableParamReserve1_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !127u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 7 bits of disableParamReserve1", value),
+));
+}
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !254u8 | bits << 1).to_le_bytes());
+Ok(())
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+pub fn disableParamReserve1_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.disableParamReserve1_set(f(self.disableParamReserve1_get()))
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 1u8) >> 0;
+bits != 0
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !1u8 | bits << 0).to_le_bytes());
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isRefRightArts_set(f(self.isRefRightArts_get()));
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 2u8) >> 1;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !2u8 | bits << 1).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutLeftHand_set(f(self.isGrayoutLeftHand_get()));
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 4u8) >> 2;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !4u8 | bits << 2).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutRightHand_set(f(self.isGrayoutRightHand_get()));
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 8u8) >> 3;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !8u8 | bits << 3).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutBothHand_set(f(self.isGrayoutBothHand_get()));
+}
+/// ### Display Name
+/// 予約領域
+pub fn reserve2_get(&self) -> u8 {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 240u8) >> 4;
+bits as u8
+}
+/// ### Display Name
+/// 予約領域
+/// ### Errors
+/// If the value does not fit in 4 bits.
+pub fn reserve2_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !15u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 4 bits of reserve2", value),
+));
+}
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !240u8 | bits << 4).to_le_bytes());
+Ok(())
+}
+/// ### Display Name
+/// 予約領域
+pub fn reserve2_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.reserve2_set(f(self.reserve2_get()))
+}
+}
+impl Default for SWORD_ARTS_PARAM_ST {
+fn default() -> Self {
+Self {
+_bitfield1: [0; 1],
+disableParamReserve2: [0; 3],
+swordArtsType: 0,
+artsSpeedType: 0,
+refStatus: SWORD_ARTS_REF_STATUS_TYPE(0),
+_bitfield2: [0; 1],
+usePoint_L1: 0,
+usePoint_L2: 0,
+usePoint_R1: 0,
+usePoint_R2: 0,
+textId: TitleSwordArtsFmgId(0),
+useMagicPoint_L1: 0,
+useMagicPoint_L2: 0,
+useMagicPoint_R1: 0,
+useMagicPoint_R2: 0,
+shieldIconType: SWORD_ARTS_SHIELD_ICON_TYPE(0),
+pad: [0; 1],
+iconId: 0,
+aiUsageId: -1
+}
+}
+}
+const _: () = assert!(::std::mem::size_of::<SWORD_ARTS_PARAM_ST>() == 32);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, disableParamReserve2) == 1);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, swordArtsType) == 4);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, artsSpeedType) == 5);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, refStatus) == 6);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_L1) == 8);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_L2) == 9);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_R1) == 10);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_R2) == 11);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, textId) == 12);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_L1) == 16);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_L2) == 18);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_R1) == 20);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_R2) == 22);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, shieldIconType) == 24);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, pad) == 25);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, iconId) == 26);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, aiUsageId) == 28);
+unsafe impl ::paramdef_codegen::param::ParamRow for SWORD_ARTS_PARAM_ST {}
+impl<'a> TryFrom<&::paramdef_codegen::param::Row<'a>> for &'a SWORD_ARTS_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(row: &::paramdef_codegen::param::Row<'a>) -> ::std::io::Result<Self> {
+::paramdef_codegen::param::ParamRow::from_row(row)
+}
+}
+}
+/// Layout of the param from DefsPatch version 10701000.
+pub mod v10701000 {
+#[allow(unused_imports)]
+use super::*;
+/// Defines Ashes of War, including unique AoW. Determines AoW animation.\nBehaviour data remains in BehaviourParam. Unique AoW data is with the weapon and not with other AoW data.
+#[repr(C)]
+pub struct SWORD_ARTS_PARAM_ST {
+_bitfield1: [u8; 1],
+/// ### Display Name
+/// パッケージ出力用リザーブ2
+/// ### Description
+/// パッケージ出力用リザーブ2
+disableParamReserve2: [u8; 3],
+/// Offset for TAE animation. 0 is a600. To pass to the behavior script to determine which swashbuckler
+/// ### Display Name
+/// 剣戟ID
+/// ### Description
+/// ビヘイビアスクリプトに渡してどの剣戟か判定するためのもの
+pub swordArtsType: u8,
+/// Which cancellation timing do you see? 0: Normal (left hand attack) / 1: Early / 2: Slow
+/// ### Display Name
+/// アーツ速度
+/// ### Description
+/// どのキャンセルタイミングを見るか。0：通常（左手攻撃）／1：早い／2：遅い
+/// ### Maximum
+/// 2
+pub artsSpeedType: u8,
+/// Which system of arts points to refer to
+/// ### Display Name
+/// 関連ステータス
+/// ### Description
+/// どの系統のアーツポイントを参照するか
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 128
+pub refStatus: SWORD_ARTS_REF_STATUS_TYPE,
+_bitfield2: [u8; 1],
+/// Points to spend when putting out arts by L1
+/// ### Display Name
+/// 消費ポイント L1
+/// ### Description
+/// L1によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_L1: i8,
+/// Points to spend when putting out arts by L2
+/// ### Display Name
+/// 消費ポイント L2
+/// ### Description
+/// L2によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_L2: i8,
+/// Points to spend when putting out arts by R1
+/// ### Display Name
+/// 消費ポイント R1
+/// ### Description
+/// R1によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_R1: i8,
+/// Points to spend when putting out arts by R2
+/// ### Display Name
+/// 消費ポイント R2
+/// ### Description
+/// R2によりアーツを出したときに消費するポイント
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 99
+pub usePoint_R2: i8,
+/// Text ID for arts description
+/// ### Text
+/// FMG `TitleSwordArts`
+/// ### Display Name
+/// テキストID
+/// ### Description
+/// アーツ説明用のテキストID
+/// ### Minimum
+/// 0
+/// ### Maximum
+/// 999999999
+pub textId: TitleSwordArtsFmgId<i32>,
+/// MP consumed when issuing arts by L1
+/// ### Display Name
+/// 消費MP L1
+/// ### Description
+/// L1によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_L1: i16,
+/// MP consumed when issuing arts by L2
+/// ### Display Name
+/// 消費MP L2
+/// ### Description
+/// L2によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_L2: i16,
+/// MP consumed when issuing arts by R1
+/// ### Display Name
+/// 消費MP R1
+/// ### Description
+/// R1によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_R1: i16,
+/// MP consumed when issuing arts by R2
+/// ### Display Name
+/// 消費MP R2
+/// ### Description
+/// R2によりアーツを出したときに消費するMP
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999
+pub useMagicPoint_R2: i16,
+/// Offset for TAE animation. 0 is a600. To pass to the behavior script to determine which swashbuckler
+pub swordArtsTypeNew: u8,
+pad: [u8; 1],
+/// ID of the icon displayed in FE etc.
+/// ### Display Name
+/// アイコンID
+/// ### Description
+/// FEなどで表示するアイコンのID
+pub iconId: u16,
+/// AI usage judgment ID
+/// ### Display Name
+/// AI使用判断ID
+/// ### Description
+/// AI使用判断ID
+/// ### Minimum
+/// -1
+/// ### Maximum
+/// 999999999
+pub aiUsageId: i32,
+}
+impl SWORD_ARTS_PARAM_ST {
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 1u8) >> 0;
+bits != 0
+}
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !1u8 | bits << 0).to_le_bytes());
+}
+/// Parameters marked with  are excluded in the NT version package.
+/// ### Display Name
+/// NT版出力から外すか
+/// ### Description
+/// ○をつけたパラメータをNT版パッケージでは除外します
+/// ### Maximum
+/// 1
+pub fn disableParam_NT_update(&mut self, f: impl Fn(bool) -> bool) {
+self.disableParam_NT_set(f(self.disableParam_NT_get()));
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+pub fn disableParamReserve1_get(&self) -> u8 {
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+let bits = (storage & 254u8) >> 1;
+bits as u8
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+/// ### Errors
+/// If the value does not fit in 7 bits.
+pub fn disableParamReserve1_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !127u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 7 bits of disableParamReserve1", value),
+));
+}
+let storage = u8::from_le_bytes(self._bitfield1[0..1].try_into().unwrap());
+self._bitfield1[0..1].copy_from_slice(&(storage & !254u8 | bits << 1).to_le_bytes());
+Ok(())
+}
+/// ### Display Name
+/// パッケージ出力用リザーブ1
+/// ### Description
+/// パッケージ出力用リザーブ1
+pub fn disableParamReserve1_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.disableParamReserve1_set(f(self.disableParamReserve1_get()))
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 1u8) >> 0;
+bits != 0
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !1u8 | bits << 0).to_le_bytes());
+}
+/// When set to the arts of the left-handed weapon, the arts of the right-handed weapon are displayed in FE. Assumed to be used for weapon maneuvers etc.
+/// ### Display Name
+/// 左手（片手持ち）時に右手のアーツを表示するか
+/// ### Description
+/// 左手武器のアーツに設定されている場合、右手武器のアーツをFEに表示します。「武器戦技」などに使われる想定
+/// ### Maximum
+/// 1
+pub fn isRefRightArts_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isRefRightArts_set(f(self.isRefRightArts_get()));
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 2u8) >> 1;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !2u8 | bits << 1).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of the left hand (one-handed)
+/// ### Display Name
+/// 左手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 左手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutLeftHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutLeftHand_set(f(self.isGrayoutLeftHand_get()));
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 4u8) >> 2;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !4u8 | bits << 2).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of the right hand (one-handed)
+/// ### Display Name
+/// 右手（片手持ち）時にグレーアウトするか
+/// ### Description
+/// 右手（片手持ち）のアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutRightHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutRightHand_set(f(self.isGrayoutRightHand_get()));
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_get(&self) -> bool {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 8u8) >> 3;
+bits != 0
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_set(&mut self, value: bool) {
+let bits: u8 = value as _;
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !8u8 | bits << 3).to_le_bytes());
+}
+/// Whether to gray out when displaying the arts name of both hands
+/// ### Display Name
+/// 両手持ち時にグレーアウトするか
+/// ### Description
+/// 両手持ちのアーツ名を表示するときにグレーアウトするか
+/// ### Maximum
+/// 1
+pub fn isGrayoutBothHand_update(&mut self, f: impl Fn(bool) -> bool) {
+self.isGrayoutBothHand_set(f(self.isGrayoutBothHand_get()));
+}
+/// ### Display Name
+/// 予約領域
+pub fn reserve2_get(&self) -> u8 {
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+let bits = (storage & 240u8) >> 4;
+bits as u8
+}
+/// ### Display Name
+/// 予約領域
+/// ### Errors
+/// If the value does not fit in 4 bits.
+pub fn reserve2_set(&mut self, value: u8) -> ::std::io::Result<()> {
+let bits: u8 = value as _;
+if bits & !15u8 != 0 {
+return Err(::std::io::Error::new(
+::std::io::ErrorKind::InvalidInput,
+format!("{:?} does not fit in the 4 bits of reserve2", value),
+));
+}
+let storage = u8::from_le_bytes(self._bitfield2[0..1].try_into().unwrap());
+self._bitfield2[0..1].copy_from_slice(&(storage & !240u8 | bits << 4).to_le_bytes());
+Ok(())
+}
+/// ### Display Name
+/// 予約領域
+pub fn reserve2_update(&mut self, f: impl Fn(u8) -> u8) -> ::std::io::Result<()> {
+self.reserve2_set(f(self.reserve2_get()))
+}
+}
+impl Default for SWORD_ARTS_PARAM_ST {
+fn default() -> Self {
+Self {
+_bitfield1: [0; 1],
+disableParamReserve2: [0; 3],
+swordArtsType: 0,
+artsSpeedType: 0,
+refStatus: SWORD_ARTS_REF_STATUS_TYPE(0),
+_bitfield2: [0; 1],
+usePoint_L1: 0,
+usePoint_L2: 0,
+usePoint_R1: 0,
+usePoint_R2: 0,
+textId: TitleSwordArtsFmgId(0),
+useMagicPoint_L1: 0,
+useMagicPoint_L2: 0,
+useMagicPoint_R1: 0,
+useMagicPoint_R2: 0,
+swordArtsTypeNew: 0,
+pad: [0; 1],
+iconId: 0,
+aiUsageId: -1
+}
+}
+}
+const _: () = assert!(::std::mem::size_of::<SWORD_ARTS_PARAM_ST>() == 32);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, disableParamReserve2) == 1);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, swordArtsType) == 4);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, artsSpeedType) == 5);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, refStatus) == 6);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_L1) == 8);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_L2) == 9);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_R1) == 10);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, usePoint_R2) == 11);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, textId) == 12);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_L1) == 16);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_L2) == 18);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_R1) == 20);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, useMagicPoint_R2) == 22);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, swordArtsTypeNew) == 24);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, pad) == 25);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, iconId) == 26);
+const _: () = assert!(::std::mem::offset_of!(SWORD_ARTS_PARAM_ST, aiUsageId) == 28);
+unsafe impl ::paramdef_codegen::param::ParamRow for SWORD_ARTS_PARAM_ST {}
+impl<'a> TryFrom<&::paramdef_codegen::param::Row<'a>> for &'a SWORD_ARTS_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(row: &::paramdef_codegen::param::Row<'a>) -> ::std::io::Result<Self> {
+::paramdef_codegen::param::ParamRow::from_row(row)
+}
+}
+}
+pub use v10701000::SWORD_ARTS_PARAM_ST;
+impl TryFrom<&v0::SWORD_ARTS_PARAM_ST> for v10701000::SWORD_ARTS_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(value: &v0::SWORD_ARTS_PARAM_ST) -> ::std::io::Result<Self> {
+let mut row = Self::default();
+row.disableParam_NT_set(value.disableParam_NT_get());
+row.swordArtsType = value.swordArtsType;
+row.artsSpeedType = value.artsSpeedType;
+row.refStatus = value.refStatus;
+row.isRefRightArts_set(value.isRefRightArts_get());
+row.isGrayoutLeftHand_set(value.isGrayoutLeftHand_get());
+row.isGrayoutRightHand_set(value.isGrayoutRightHand_get());
+row.isGrayoutBothHand_set(value.isGrayoutBothHand_get());
+row.usePoint_L1 = value.usePoint_L1;
+row.usePoint_L2 = value.usePoint_L2;
+row.usePoint_R1 = value.usePoint_R1;
+row.usePoint_R2 = value.usePoint_R2;
+row.textId = value.textId;
+row.useMagicPoint_L1 = value.useMagicPoint_L1;
+row.useMagicPoint_L2 = value.useMagicPoint_L2;
+row.useMagicPoint_R1 = value.useMagicPoint_R1;
+row.useMagicPoint_R2 = value.useMagicPoint_R2;
+row.iconId = value.iconId;
+row.aiUsageId = value.aiUsageId;
+Ok(row)
+}
+}
+impl TryFrom<&v10701000::SWORD_ARTS_PARAM_ST> for v0::SWORD_ARTS_PARAM_ST {
+type Error = ::std::io::Error;
+fn try_from(value: &v10701000::SWORD_ARTS_PARAM_ST) -> ::std::io::Result<Self> {
+let mut row = Self::default();
+row.disableParam_NT_set(value.disableParam_NT_get());
+row.swordArtsType = value.swordArtsType;
+row.artsSpeedType = value.artsSpeedType;
+row.refStatus = value.refStatus;
+row.isRefRightArts_set(value.isRefRightArts_get());
+row.isGrayoutLeftHand_set(value.isGrayoutLeftHand_get());
+row.isGrayoutRightHand_set(value.isGrayoutRightHand_get());
+row.isGrayoutBothHand_set(value.isGrayoutBothHand_get());
+row.usePoint_L1 = value.usePoint_L1;
+row.usePoint_L2 = value.usePoint_L2;
+row.usePoint_R1 = value.usePoint_R1;
+row.usePoint_R2 = value.usePoint_R2;
+row.textId = value.textId;
+row.useMagicPoint_L1 = value.useMagicPoint_L1;
+row.useMagicPoint_L2 = value.useMagicPoint_L2;
+row.useMagicPoint_R1 = value.useMagicPoint_R1;
+row.useMagicPoint_R2 = value.useMagicPoint_R2;
+row.iconId = value.iconId;
+row.aiUsageId = value.aiUsageId;
+Ok(row)
+}
+}
+/// Row IDs and names of the `SwordArtsParam` param, from the paramdex names.
+pub mod SwordArtsParam {
+pub mod ids {
+/// No Skill
+pub const NO_SKILL: u32 = 10;
+/// Lion's Claw
+pub const LIONS_CLAW: u32 = 100;
+/// Impaling Thrust
+pub const IMPALING_THRUST: u32 = 101;
+/// Piercing Fang
+pub const PIERCING_FANG: u32 = 102;
+/// Spinning Slash
+pub const SPINNING_SLASH: u32 = 103;
+/// %null%
+pub const NULL_104: u32 = 104;
+/// Charge Forth
+pub const CHARGE_FORTH: u32 = 105;
+/// Stamp (Upward Cut)
+pub const STAMP_UPWARD_CUT: u32 = 106;
+/// Stamp (Sweep)
+pub const STAMP_SWEEP: u32 = 107;
+/// Blood Tax
+pub const BLOOD_TAX: u32 = 108;
+/// Repeating Thrust
+pub const REPEATING_THRUST: u32 = 109;
+/// Wild Strikes
+pub const WILD_STRIKES: u32 = 110;
+/// Spinning Strikes
+pub const SPINNING_STRIKES: u32 = 111;
+/// Double Slash
+pub const DOUBLE_SLASH: u32 = 112;
+/// Prelate's Charge
+pub const PRELATES_CHARGE: u32 = 113;
+/// Unsheathe
+pub const UNSHEATHE: u32 = 114;
+/// Square Off
+pub const SQUARE_OFF: u32 = 115;
+/// Giant Hunt
+pub const GIANT_HUNT: u32 = 116;
+/// Torch Attack
+pub const TORCH_ATTACK: u32 = 117;
+/// Loretta's Slash
+pub const LORETTAS_SLASH: u32 = 118;
+/// Poison Moth Flight
+pub const POISON_MOTH_FLIGHT: u32 = 119;
+/// Spinning Weapon
+pub const SPINNING_WEAPON_120: u32 = 120;
+/// %null%
+pub const NULL_121: u32 = 121;
+/// Storm Assault
+pub const STORM_ASSAULT: u32 = 122;
+/// Stormcaller
+pub const STORMCALLER: u32 = 123;
+/// Sword Dance
+pub const SWORD_DANCE: u32 = 124;
+/// Spinning Chain
+pub const SPINNING_CHAIN: u32 = 125;
+/// Glintblade Phalanx
+pub const GLINTBLADE_PHALANX: u32 = 200;
+/// Sacred Blade
+pub const SACRED_BLADE: u32 = 201;
+/// Ice Spear
+pub const ICE_SPEAR: u32 = 202;
+/// Glintstone Pebble
+pub const GLINTSTONE_PEBBLE: u32 = 203;
+/// Bloody Slash
+pub const BLOODY_SLASH: u32 = 204;
+/// Lifesteal Fist
+pub const LIFESTEAL_FIST: u32 = 205;
+/// Eruption
+pub const ERUPTION: u32 = 207;
+/// Prayerful Strike
+pub const PRAYERFUL_STRIKE: u32 = 208;
+/// Gravitas
+pub const GRAVITAS: u32 = 209;
+/// Storm Blade
+pub const STORM_BLADE: u32 = 210;
+/// Earthshaker
+pub const EARTHSHAKER: u32 = 212;
+/// Golden Land
+pub const GOLDEN_LAND: u32 = 213;
+/// Flaming Strike
+pub const FLAMING_STRIKE: u32 = 214;
+/// Thunderbolt
+pub const THUNDERBOLT: u32 = 216;
+/// Lightning Slash
+pub const LIGHTNING_SLASH: u32 = 217;
+/// Carian Grandeur
+pub const CARIAN_GRANDEUR: u32 = 218;
+/// Carian Greatsword
+pub const CARIAN_GREATSWORD: u32 = 219;
+/// Vacuum Slice
+pub const VACUUM_SLICE: u32 = 220;
+/// Black Flame Tornado
+pub const BLACK_FLAME_TORNADO: u32 = 221;
+/// Sacred Ring of Light
+pub const SACRED_RING_OF_LIGHT: u32 = 222;
+/// Firebreather
+pub const FIREBREATHER: u32 = 223;
+/// Blood Blade
+pub const BLOOD_BLADE: u32 = 224;
+/// Phantom Slash
+pub const PHANTOM_SLASH: u32 = 225;
+/// Spectral Lance
+pub const SPECTRAL_LANCE: u32 = 226;
+/// Chilling Mist
+pub const CHILLING_MIST: u32 = 227;
+/// Poisonous Mist
+pub const POISONOUS_MIST: u32 = 228;
+/// Shield Bash
+pub const SHIELD_BASH: u32 = 300;
+/// Barricade Shield
+pub const BARRICADE_SHIELD: u32 = 301;
+/// Parry
+pub const PARRY: u32 = 302;
+/// Buckler Parry
+pub const BUCKLER_PARRY: u32 = 303;
+/// Carian Retaliation
+pub const CARIAN_RETALIATION: u32 = 305;
+/// Storm Wall
+pub const STORM_WALL: u32 = 306;
+/// Golden Parry
+pub const GOLDEN_PARRY: u32 = 307;
+/// Shield Crash
+pub const SHIELD_CRASH: u32 = 308;
+/// Thops's Barrier
+pub const THOPSS_BARRIER: u32 = 309;
+/// Through and Through
+pub const THROUGH_AND_THROUGH: u32 = 400;
+/// Barrage
+pub const BARRAGE: u32 = 401;
+/// Mighty Shot
+pub const MIGHTY_SHOT: u32 = 402;
+/// Enchanted Shot
+pub const ENCHANTED_SHOT: u32 = 404;
+/// Sky Shot
+pub const SKY_SHOT: u32 = 405;
+/// Rain of Arrows
+pub const RAIN_OF_ARROWS: u32 = 406;
+/// %null%
+pub const NULL_407: u32 = 407;
+/// Hoarfrost Stomp
+pub const HOARFROST_STOMP: u32 = 501;
+/// Storm Stomp
+pub const STORM_STOMP: u32 = 502;
+/// Kick
+pub const KICK: u32 = 503;
+/// Lightning Ram
+pub const LIGHTNING_RAM: u32 = 504;
+/// Flame of the Redmanes
+pub const FLAME_OF_THE_REDMANES: u32 = 505;
+/// Ground Slam
+pub const GROUND_SLAM: u32 = 506;
+/// Golden Slam
+pub const GOLDEN_SLAM: u32 = 507;
+/// Waves of Darkness
+pub const WAVES_OF_DARKNESS: u32 = 508;
+/// Hoarah Loux's Earthshaker
+pub const HOARAH_LOUXS_EARTHSHAKER: u32 = 509;
+/// Determination
+pub const DETERMINATION: u32 = 600;
+/// Royal Knight's Resolve
+pub const ROYAL_KNIGHTS_RESOLVE: u32 = 601;
+/// Assassin's Gambit
+pub const ASSASSINS_GAMBIT: u32 = 602;
+/// Golden Vow
+pub const GOLDEN_VOW: u32 = 603;
+/// Sacred Order
+pub const SACRED_ORDER: u32 = 604;
+/// Shared Order
+pub const SHARED_ORDER: u32 = 605;
+/// Seppuku
+pub const SEPPUKU: u32 = 606;
+/// Cragblade
+pub const CRAGBLADE: u32 = 607;
+/// Barbaric Roar
+pub const BARBARIC_ROAR: u32 = 650;
+/// War Cry
+pub const WAR_CRY: u32 = 651;
+/// Beast's Roar
+pub const BEASTS_ROAR: u32 = 652;
+/// Troll's Roar
+pub const TROLLS_ROAR: u32 = 653;
+/// Braggart's Roar
+pub const BRAGGARTS_ROAR: u32 = 654;
+/// Endure
+pub const ENDURE: u32 = 700;
+/// Vow of the Indomitable
+pub const VOW_OF_THE_INDOMITABLE: u32 = 701;
+/// Holy Ground
+pub const HOLY_GROUND: u32 = 702;
+/// Quickstep
+pub const QUICKSTEP: u32 = 800;
+/// Bloodhound's Step
+pub const BLOODHOUNDS_STEP: u32 = 801;
+/// Raptor of the Mists
+pub const RAPTOR_OF_THE_MISTS: u32 = 802;
+/// White Shadow's Lure
+pub const WHITE_SHADOWS_LURE: u32 = 850;
+/// Surge of Faith
+pub const SURGE_OF_FAITH: u32 = 1000;
+/// Flame Spit
+pub const FLAME_SPIT: u32 = 1001;
+/// Tongues of Fire
+pub const TONGUES_OF_FIRE: u32 = 1002;
+/// Oracular Bubble
+pub const ORACULAR_BUBBLE: u32 = 1003;
+/// Bubble Shower
+pub const BUBBLE_SHOWER: u32 = 1004;
+/// Great Oracular Bubble
+pub const GREAT_ORACULAR_BUBBLE: u32 = 1005;
+/// Sea of Magma
+pub const SEA_OF_MAGMA: u32 = 1006;
+/// Viper Bite
+pub const VIPER_BITE: u32 = 1007;
+/// Moonlight Greatsword
+pub const MOONLIGHT_GREATSWORD: u32 = 1008;
+/// Siluria's Woe
+pub const SILURIAS_WOE: u32 = 1009;
+/// Rallying Standard
+pub const RALLYING_STANDARD: u32 = 1010;
+/// Bear Witness!
+pub const BEAR_WITNESS: u32 = 1011;
+/// Eochaid's Dancing Blade
+pub const EOCHAIDS_DANCING_BLADE_1012: u32 = 1012;
+/// Soul Stifler
+pub const SOUL_STIFLER: u32 = 1013;
+/// Taker's Flames
+pub const TAKERS_FLAMES: u32 = 1014;
+/// Shriek of Milos
+pub const SHRIEK_OF_MILOS: u32 = 1015;
+/// Reduvia Blood Blade
+pub const REDUVIA_BLOOD_BLADE: u32 = 1016;
+/// Glintstone Dart
+pub const GLINTSTONE_DART: u32 = 1017;
+/// Flowing Form
+pub const FLOWING_FORM_1018: u32 = 1018;
+/// Night-and-Flame Stance
+pub const NIGHT_AND_FLAME_STANCE: u32 = 1019;
+/// Wave of Gold
+pub const WAVE_OF_GOLD: u32 = 1020;
+/// Ruinous Ghostflame
+pub const RUINOUS_GHOSTFLAME: u32 = 1021;
+/// Establish Order
+pub const ESTABLISH_ORDER: u32 = 1022;
+/// Mists of Slumber
+pub const MISTS_OF_SLUMBER: u32 = 1023;
+/// Spearcall Ritual
+pub const SPEARCALL_RITUAL: u32 = 1024;
+/// Wolf's Assault
+pub const WOLFS_ASSAULT: u32 = 1025;
+/// Thundercloud Form
+pub const THUNDERCLOUD_FORM: u32 = 1026;
+/// Cursed-Blood Slice
+pub const CURSED_BLOOD_SLICE: u32 = 1027;
+/// Waterfowl Dance
+pub const WATERFOWL_DANCE: u32 = 1028;
+/// Gold Breaker
+pub const GOLD_BREAKER: u32 = 1029;
+/// I Command Thee Kneel!
+pub const I_COMMAND_THEE_KNEEL: u32 = 1030;
+/// Regal Roar
+pub const REGAL_ROAR: u32 = 1031;
+/// Starcaller Cry
+pub const STARCALLER_CRY: u32 = 1032;
+/// Wave of Destruction
+pub const WAVE_OF_DESTRUCTION: u32 = 1033;
+/// Bloodboon Ritual
+pub const BLOODBOON_RITUAL: u32 = 1034;
+/// Flowing Form
+pub const FLOWING_FORM_1035: u32 = 1035;
+/// Blade of Death
+pub const BLADE_OF_DEATH: u32 = 1036;
+/// Blade of Gold
+pub const BLADE_OF_GOLD: u32 = 1037;
+/// Destined Death
+pub const DESTINED_DEATH: u32 = 1038;
+/// Spinning Wheel
+pub const SPINNING_WHEEL: u32 = 1039;
+/// Alabaster Lords' Pull
+pub const ALABASTER_LORDS_PULL: u32 = 1040;
+/// Onyx Lords' Repulsion
+pub const ONYX_LORDS_REPULSION: u32 = 1041;
+/// Oath of Vengeance
+pub const OATH_OF_VENGEANCE: u32 = 1042;
+/// Ice Lightning Sword
+pub const ICE_LIGHTNING_SWORD: u32 = 1043;
+/// Regal Beastclaw
+pub const REGAL_BEASTCLAW: u32 = 1044;
+/// Flame Dance
+pub const FLAME_DANCE: u32 = 1045;
+/// Claw Flick
+pub const CLAW_FLICK: u32 = 1046;
+/// Nebula
+pub const NEBULA_1047: u32 = 1047;
+/// Ghostflame Ignition
+pub const GHOSTFLAME_IGNITION: u32 = 1048;
+/// Ancient Lightning Spear
+pub const ANCIENT_LIGHTNING_SPEAR: u32 = 1049;
+/// Frenzyflame Thrust
+pub const FRENZYFLAME_THRUST: u32 = 1050;
+/// Miquella's Ring of Light
+pub const MIQUELLAS_RING_OF_LIGHT: u32 = 1051;
+/// Golden Tempering
+pub const GOLDEN_TEMPERING: u32 = 1052;
+/// Last Rites
+pub const LAST_RITES: u32 = 1053;
+/// Unblockable Blade
+pub const UNBLOCKABLE_BLADE_1054: u32 = 1054;
+/// Eochaid's Dancing Blade
+pub const EOCHAIDS_DANCING_BLADE_1055: u32 = 1055;
+/// Corpse Wax Cutter
+pub const CORPSE_WAX_CUTTER: u32 = 1167;
+/// Zamor Ice Storm
+pub const ZAMOR_ICE_STORM: u32 = 1168;
+/// Radahn's Rain
+pub const RADAHNS_RAIN: u32 = 1169;
+/// The Queen's Black Flame
+pub const THE_QUEENS_BLACK_FLAME: u32 = 1170;
+/// Dynast's Finesse
+pub const DYNASTS_FINESSE: u32 = 1171;
+/// Magma Shower
+pub const MAGMA_SHOWER: u32 = 1172;
+/// Nebula
+pub const NEBULA_1173: u32 = 1173;
+/// Death Flare
+pub const DEATH_FLARE: u32 = 1174;
+/// Bloodhound's Finesse
+pub const BLOODHOUNDS_FINESSE: u32 = 1175;
+/// Magma Guillotine
+pub const MAGMA_GUILLOTINE: u32 = 1176;
+/// Corpse Piler
+pub const CORPSE_PILER: u32 = 1177;
+/// Transient Moonlight
+pub const TRANSIENT_MOONLIGHT: u32 = 1178;
+/// Bloodblade Dance
+pub const BLOODBLADE_DANCE: u32 = 1179;
+/// %null%
+pub const NULL_1180: u32 = 1180;
+/// %null%
+pub const NULL_1181: u32 = 1181;
+/// Knowledge Above All
+pub const KNOWLEDGE_ABOVE_ALL: u32 = 1182;
+/// Devourer of Worlds
+pub const DEVOURER_OF_WORLDS: u32 = 1183;
+/// Familal Rancor
+pub const FAMILAL_RANCOR: u32 = 1184;
+/// Rosus's Summons
+pub const ROSUSS_SUMMONS: u32 = 1185;
+/// Thunderstorm
+pub const THUNDERSTORM: u32 = 1186;
+/// Sacred Phalanx
+pub const SACRED_PHALANX: u32 = 1187;
+/// Great-Serpent Hunt
+pub const GREAT_SERPENT_HUNT: u32 = 1188;
+/// Angel's Wings
+pub const ANGELS_WINGS: u32 = 1189;
+/// Storm Kick
+pub const STORM_KICK: u32 = 1190;
+/// Unblockable Blade
+pub const UNBLOCKABLE_BLADE_1191: u32 = 1191;
+/// Sorcery of the Crozier
+pub const SORCERY_OF_THE_CROZIER: u32 = 1192;
+/// Erdtree Slam
+pub const ERDTREE_SLAM: u32 = 1193;
+/// Gravity Bolt
+pub const GRAVITY_BOLT: u32 = 1194;
+/// Fires of Slumber
+pub const FIRES_OF_SLUMBER: u32 = 1195;
+/// Golden Retaliation
+pub const GOLDEN_RETALIATION: u32 = 1196;
+/// Contagious Fury
+pub const CONTAGIOUS_FURY: u32 = 1197;
+/// Ordovis's Vortex
+pub const ORDOVISS_VORTEX: u32 = 1198;
+/// Spinning Weapon
+pub const SPINNING_WEAPON_1199: u32 = 1199;
+}
+/// Row names by ID.
+pub static NAMES: ::paramdef_codegen::phf::Map<u32, &'static str> = ::paramdef_codegen::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 26),
+        (0, 1),
+        (0, 47),
+        (1, 24),
+        (0, 54),
+        (0, 1),
+        (3, 42),
+        (0, 85),
+        (0, 157),
+        (1, 16),
+        (0, 39),
+        (0, 32),
+        (0, 2),
+        (0, 0),
+        (1, 1),
+        (1, 18),
+        (0, 166),
+        (0, 15),
+        (0, 7),
+        (0, 2),
+        (0, 0),
+        (1, 91),
+        (0, 97),
+        (0, 144),
+        (0, 18),
+        (3, 86),
+        (15, 97),
+        (40, 145),
+        (1, 116),
+        (2, 84),
+        (31, 112),
+        (0, 49),
+        (2, 42),
+        (0, 1),
+        (0, 87),
+        (0, 4),
+        (166, 16),
+        (37, 79),
+    ],
+    entries: &[
+        (604, "Sacred Order"),
+        (309, "Thops's Barrier"),
+        (125, "Spinning Chain"),
+        (1054, "Unblockable Blade"),
+        (1034, "Bloodboon Ritual"),
+        (1019, "Night-and-Flame Stance"),
+        (208, "Prayerful Strike"),
+        (1178, "Transient Moonlight"),
+        (216, "Thunderbolt"),
+        (1172, "Magma Shower"),
+        (223, "Firebreather"),
+        (601, "Royal Knight's Resolve"),
+        (113, "Prelate's Charge"),
+        (122, "Storm Assault"),
+        (1049, "Ancient Lightning Spear"),
+        (508, "Waves of Darkness"),
+        (501, "Hoarfrost Stomp"),
+        (507, "Golden Slam"),
+        (1002, "Tongues of Fire"),
+        (100, "Lion's Claw"),
+        (1185, "Rosus's Summons"),
+        (1055, "Eochaid's Dancing Blade"),
+        (1048, "Ghostflame Ignition"),
+        (603, "Golden Vow"),
+        (1020, "Wave of Gold"),
+        (301, "Barricade Shield"),
+        (1010, "Rallying Standard"),
+        (653, "Troll's Roar"),
+        (124, "Sword Dance"),
+        (605, "Shared Order"),
+        (1167, "Corpse Wax Cutter"),
+        (1182, "Knowledge Above All"),
+        (305, "Carian Retaliation"),
+        (1012, "Eochaid's Dancing Blade"),
+        (504, "Lightning Ram"),
+        (402, "Mighty Shot"),
+        (202, "Ice Spear"),
+        (214, "Flaming Strike"),
+        (1175, "Bloodhound's Finesse"),
+        (651, "War Cry"),
+        (1006, "Sea of Magma"),
+        (1027, "Cursed-Blood Slice"),
+        (120, "Spinning Weapon"),
+        (1183, "Devourer of Worlds"),
+        (1190, "Storm Kick"),
+        (1004, "Bubble Shower"),
+        (109, "Repeating Thrust"),
+        (1028, "Waterfowl Dance"),
+        (1003, "Oracular Bubble"),
+        (228, "Poisonous Mist"),
+        (1041, "Onyx Lords' Repulsion"),
+        (503, "Kick"),
+        (201, "Sacred Blade"),
+        (227, "Chilling Mist"),
+        (221, "Black Flame Tornado"),
+        (502, "Storm Stomp"),
+        (1039, "Spinning Wheel"),
+        (1181, "%null%"),
+        (200, "Glintblade Phalanx"),
+        (1032, "Starcaller Cry"),
+        (400, "Through and Through"),
+        (1188, "Great-Serpent Hunt"),
+        (105, "Charge Forth"),
+        (1180, "%null%"),
+        (220, "Vacuum Slice"),
+        (1001, "Flame Spit"),
+        (1192, "Sorcery of the Crozier"),
+        (1047, "Nebula"),
+        (1031, "Regal Roar"),
+        (103, "Spinning Slash"),
+        (1037, "Blade of Gold"),
+        (802, "Raptor of the Mists"),
+        (701, "Vow of the Indomitable"),
+        (114, "Unsheathe"),
+        (1022, "Establish Order"),
+        (652, "Beast's Roar"),
+        (226, "Spectral Lance"),
+        (1007, "Viper Bite"),
+        (1043, "Ice Lightning Sword"),
+        (1186, "Thunderstorm"),
+        (307, "Golden Parry"),
+        (213, "Golden Land"),
+        (1191, "Unblockable Blade"),
+        (117, "Torch Attack"),
+        (106, "Stamp (Upward Cut)"),
+        (1050, "Frenzyflame Thrust"),
+        (1017, "Glintstone Dart"),
+        (404, "Enchanted Shot"),
+        (1196, "Golden Retaliation"),
+        (207, "Eruption"),
+        (203, "Glintstone Pebble"),
+        (116, "Giant Hunt"),
+        (606, "Seppuku"),
+        (1179, "Bloodblade Dance"),
+        (1023, "Mists of Slumber"),
+        (1025, "Wolf's Assault"),
+        (300, "Shield Bash"),
+        (104, "%null%"),
+        (217, "Lightning Slash"),
+        (204, "Bloody Slash"),
+        (1021, "Ruinous Ghostflame"),
+        (702, "Holy Ground"),
+        (225, "Phantom Slash"),
+        (1035, "Flowing Form"),
+        (1194, "Gravity Bolt"),
+        (209, "Gravitas"),
+        (401, "Barrage"),
+        (600, "Determination"),
+        (1173, "Nebula"),
+        (850, "White Shadow's Lure"),
+        (1199, "Spinning Weapon"),
+        (108, "Blood Tax"),
+        (1030, "I Command Thee Kneel!"),
+        (1033, "Wave of Destruction"),
+        (212, "Earthshaker"),
+        (1171, "Dynast's Finesse"),
+        (1013, "Soul Stifler"),
+        (406, "Rain of Arrows"),
+        (800, "Quickstep"),
+        (1198, "Ordovis's Vortex"),
+        (1009, "Siluria's Woe"),
+        (110, "Wild Strikes"),
+        (218, "Carian Grandeur"),
+        (102, "Piercing Fang"),
+        (119, "Poison Moth Flight"),
+        (1018, "Flowing Form"),
+        (1176, "Magma Guillotine"),
+        (1029, "Gold Breaker"),
+        (1024, "Spearcall Ritual"),
+        (205, "Lifesteal Fist"),
+        (107, "Stamp (Sweep)"),
+        (308, "Shield Crash"),
+        (1045, "Flame Dance"),
+        (1040, "Alabaster Lords' Pull"),
+        (111, "Spinning Strikes"),
+        (1169, "Radahn's Rain"),
+        (224, "Blood Blade"),
+        (1014, "Taker's Flames"),
+        (1046, "Claw Flick"),
+        (1044, "Regal Beastclaw"),
+        (10, "No Skill"),
+        (1015, "Shriek of Milos"),
+        (115, "Square Off"),
+        (222, "Sacred Ring of Light"),
+        (1184, "Familal Rancor"),
+        (405, "Sky Shot"),
+        (506, "Ground Slam"),
+        (1168, "Zamor Ice Storm"),
+        (210, "Storm Blade"),
+        (1195, "Fires of Slumber"),
+        (1051, "Miquella's Ring of Light"),
+        (101, "Impaling Thrust"),
+        (650, "Barbaric Roar"),
+        (1026, "Thundercloud Form"),
+        (219, "Carian Greatsword"),
+        (1008, "Moonlight Greatsword"),
+        (607, "Cragblade"),
+        (509, "Hoarah Loux's Earthshaker"),
+        (1189, "Angel's Wings"),
+        (121, "%null%"),
+        (700, "Endure"),
+        (602, "Assassin's Gambit"),
+        (1193, "Erdtree Slam"),
+        (123, "Stormcaller"),
+        (1052, "Golden Tempering"),
+        (505, "Flame of the Redmanes"),
+        (303, "Buckler Parry"),
+        (1038, "Destined Death"),
+        (112, "Double Slash"),
+        (654, "Braggart's Roar"),
+        (1177, "Corpse Piler"),
+        (801, "Bloodhound's Step"),
+        (1042, "Oath of Vengeance"),
+        (302, "Parry"),
+        (1174, "Death Flare"),
+        (1197, "Contagious Fury"),
+        (1053, "Last Rites"),
+        (1187, "Sacred Phalanx"),
+        (1016, "Reduvia Blood Blade"),
+        (1011, "Bear Witness!"),
+        (1005, "Great Oracular Bubble"),
+        (1036, "Blade of Death"),
+        (118, "Loretta's Slash"),
+        (1000, "Surge of Faith"),
+        (407, "%null%"),
+        (1170, "The Queen's Black Flame"),
+        (306, "Storm Wall"),
+    ],
+};
+/// Row IDs by name. If several rows have the same name, this is the lowest of their IDs.
+pub static IDS: ::paramdef_codegen::phf::Map<&'static str, u32> = ::paramdef_codegen::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 8),
+        (0, 152),
+        (0, 5),
+        (0, 12),
+        (0, 22),
+        (0, 171),
+        (2, 141),
+        (1, 5),
+        (1, 1),
+        (0, 0),
+        (0, 71),
+        (18, 127),
+        (4, 3),
+        (0, 15),
+        (0, 0),
+        (1, 13),
+        (1, 21),
+        (12, 25),
+        (0, 123),
+        (0, 59),
+        (1, 67),
+        (14, 45),
+        (0, 0),
+        (0, 12),
+        (0, 0),
+        (12, 16),
+        (0, 10),
+        (2, 40),
+        (8, 128),
+        (0, 43),
+        (0, 62),
+        (0, 144),
+        (0, 8),
+        (0, 0),
+        (26, 85),
+        (3, 156),
+    ],
+    entries: &[
+        ("Golden Tempering", 1052),
+        ("Rain of Arrows", 406),
+        ("Viper Bite", 1007),
+        ("Bloodboon Ritual", 1034),
+        ("Storm Assault", 122),
+        ("Moonlight Greatsword", 1008),
+        ("Kick", 503),
+        ("Flowing Form", 1018),
+        ("Endure", 700),
+        ("Wolf's Assault", 1025),
+        ("Spearcall Ritual", 1024),
+        ("White Shadow's Lure", 850),
+        ("Barbaric Roar", 650),
+        ("Angel's Wings", 1189),
+        ("Black Flame Tornado", 221),
+        ("Taker's Flames", 1014),
+        ("Surge of Faith", 1000),
+        ("Enchanted Shot", 404),
+        ("Waterfowl Dance", 1028),
+        ("Troll's Roar", 653),
+        ("Golden Vow", 603),
+        ("Square Off", 115),
+        ("Knowledge Above All", 1182),
+        ("Hoarfrost Stomp", 501),
+        ("Sword Dance", 124),
+        ("Shared Order", 605),
+        ("Starcaller Cry", 1032),
+        ("Radahn's Rain", 1169),
+        ("Golden Retaliation", 1196),
+        ("Gravity Bolt", 1194),
+        ("Quickstep", 800),
+        ("Vow of the Indomitable", 701),
+        ("Seppuku", 606),
+        ("Storm Stomp", 502),
+        ("Bubble Shower", 1004),
+        ("Last Rites", 1053),
+        ("Onyx Lords' Repulsion", 1041),
+        ("Poisonous Mist", 228),
+        ("Raptor of the Mists", 802),
+        ("Blade of Gold", 1037),
+        ("Flame Dance", 1045),
+        ("Ancient Lightning Spear", 1049),
+        ("Flaming Strike", 214),
+        ("Stormcaller", 123),
+        ("Golden Land", 213),
+        ("Cursed-Blood Slice", 1027),
+        ("Unsheathe", 114),
+        ("Carian Greatsword", 219),
+        ("Lion's Claw", 100),
+        ("Holy Ground", 702),
+        ("Ground Slam", 506),
+        ("Firebreather", 223),
+        ("Poison Moth Flight", 119),
+        ("Magma Guillotine", 1176),
+        ("No Skill", 10),
+        ("Alabaster Lords' Pull", 1040),
+        ("Death Flare", 1174),
+        ("Bloodhound's Step", 801),
+        ("Shield Crash", 308),
+        ("Regal Roar", 1031),
+        ("Piercing Fang", 102),
+        ("Sorcery of the Crozier", 1192),
+        ("Beast's Roar", 652),
+        ("Barrage", 401),
+        ("Establish Order", 1022),
+        ("Thunderstorm", 1186),
+        ("Tongues of Fire", 1002),
+        ("The Queen's Black Flame", 1170),
+        ("Shield Bash", 300),
+        ("Fires of Slumber", 1195),
+        ("Giant Hunt", 116),
+        ("Vacuum Slice", 220),
+        ("Shriek of Milos", 1015),
+        ("Golden Slam", 507),
+        ("Zamor Ice Storm", 1168),
+        ("Chilling Mist", 227),
+        ("Destined Death", 1038),
+        ("Oracular Bubble", 1003),
+        ("Spectral Lance", 226),
+        ("War Cry", 651),
+        ("Royal Knight's Resolve", 601),
+        ("Glintstone Pebble", 203),
+        ("Thops's Barrier", 309),
+        ("Phantom Slash", 225),
+        ("Glintstone Dart", 1017),
+        ("Thunderbolt", 216),
+        ("Mighty Shot", 402),
+        ("Waves of Darkness", 508),
+        ("Buckler Parry", 303),
+        ("Golden Parry", 307),
+        ("Sacred Phalanx", 1187),
+        ("Bloodhound's Finesse", 1175),
+        ("Gold Breaker", 1029),
+        ("Eruption", 207),
+        ("Wave of Gold", 1020),
+        ("Contagious Fury", 1197),
+        ("Magma Shower", 1172),
+        ("Eochaid's Dancing Blade", 1012),
+        ("Thundercloud Form", 1026),
+        ("Blood Blade", 224),
+        ("Repeating Thrust", 109),
+        ("Night-and-Flame Stance", 1019),
+        ("Devourer of Worlds", 1183),
+        ("Bear Witness!", 1011),
+        ("Stamp (Sweep)", 107),
+        ("Transient Moonlight", 1178),
+        ("Corpse Wax Cutter", 1167),
+        ("Hoarah Loux's Earthshaker", 509),
+        ("Cragblade", 607),
+        ("Reduvia Blood Blade", 1016),
+        ("%null%", 104),
+        ("Oath of Vengeance", 1042),
+        ("Gravitas", 209),
+        ("Charge Forth", 105),
+        ("Flame Spit", 1001),
+        ("Spinning Strikes", 111),
+        ("Rallying Standard", 1010),
+        ("Storm Blade", 210),
+        ("Lightning Slash", 217),
+        ("Storm Kick", 1190),
+        ("Flame of the Redmanes", 505),
+        ("Prelate's Charge", 113),
+        ("Lifesteal Fist", 205),
+        ("Earthshaker", 212),
+        ("I Command Thee Kneel!", 1030),
+        ("Through and Through", 400),
+        ("Blade of Death", 1036),
+        ("Sacred Order", 604),
+        ("Carian Retaliation", 305),
+        ("Assassin's Gambit", 602),
+        ("Glintblade Phalanx", 200),
+        ("Mists of Slumber", 1023),
+        ("Torch Attack", 117),
+        ("Ice Spear", 202),
+        ("Parry", 302),
+        ("Ghostflame Ignition", 1048),
+        ("Spinning Chain", 125),
+        ("Regal Beastclaw", 1044),
+        ("Storm Wall", 306),
+        ("Unblockable Blade", 1054),
+        ("Loretta's Slash", 118),
+        ("Siluria's Woe", 1009),
+        ("Corpse Piler", 1177),
+        ("Barricade Shield", 301),
+        ("Braggart's Roar", 654),
+        ("Wild Strikes", 110),
+        ("Great Oracular Bubble", 1005),
+        ("Claw Flick", 1046),
+        ("Ice Lightning Sword", 1043),
+        ("Double Slash", 112),
+        ("Sea of Magma", 1006),
+        ("Bloody Slash", 204),
+        ("Carian Grandeur", 218),
+        ("Spinning Wheel", 1039),
+        ("Familal Rancor", 1184),
+        ("Determination", 600),
+        ("Ordovis's Vortex", 1198),
+        ("Rosus's Summons", 1185),
+        ("Dynast's Finesse", 1171),
+        ("Frenzyflame Thrust", 1050),
+        ("Spinning Slash", 103),
+        ("Sky Shot", 405),
+        ("Wave of Destruction", 1033),
+        ("Nebula", 1047),
+        ("Erdtree Slam", 1193),
+        ("Ruinous Ghostflame", 1021),
+        ("Blood Tax", 108),
+        ("Great-Serpent Hunt", 1188),
+        ("Miquella's Ring of Light", 1051),
+        ("Prayerful Strike", 208),
+        ("Lightning Ram", 504),
+        ("Impaling Thrust", 101),
+        ("Soul Stifler", 1013),
+        ("Stamp (Upward Cut)", 106),
+        ("Sacred Ring of Light", 222),
+        ("Sacred Blade", 201),
+        ("Bloodblade Dance", 1179),
+        ("Spinning Weapon", 120),
+    ],
+};
+}
@@ -0,0 +1,608 @@
+//! Compiles the generated `test_param.rs`, `test_param_be.rs` and `test_param_versions.rs`
+//! samples in `tests/fixtures` and checks them against the ER regulation. `fixture_param.rs` is
+//! generated from the paramdef in `tests/fixtures/paramdex`, which covers the paramdex features
+//! no param of the paramdex uses.
+use paramdef_codegen::{
+    codegen::{CodegenParams, EnumDiagnosticKind, RustCodegen},
+    game::{Game, ER},
+    param::{OwnedParamFile, OwnedRow, ParamFile, ParamRow, Row},
+    paramdex_reader::ParamdexDB,
+    reflect::{FieldValue, ParamReflect},
+    row_serde::deserialize_into,
+};
+
+#[allow(non_camel_case_types, non_snake_case, dead_code, clippy::all)]
+mod action_button_param {
+    include!("fixtures/test_param.rs");
+}
+use action_button_param::{ActionButtonParam, ActionButtonParamId, ACTIONBUTTON_PARAM_ST};
+
+#[allow(non_camel_case_types, non_snake_case, dead_code, clippy::all)]
+mod action_button_param_be {
+    include!("fixtures/test_param_be.rs");
+}
+
+#[allow(non_camel_case_types, non_snake_case, dead_code, clippy::all)]
+mod sword_arts_param {
+    include!("fixtures/test_param_versions.rs");
+}
+
+#[allow(non_camel_case_types, non_snake_case, dead_code, clippy::all)]
+mod fixture_param {
+    include!("fixtures/fixture_param.rs");
+}
+
+const FIXTURE_PARAMDEX: &str = "tests/fixtures/paramdex";
+
+fn param_data(name: &str) -> Vec<u8> {
+    let bnd = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+    bnd.files
+        .into_iter()
+        .find(|f| f.name.as_deref().is_some_and(|n| n.ends_with(name)))
+        .unwrap()
+        .data
+}
+
+fn with_param(name: &str, f: impl FnOnce(&ParamFile)) {
+    f(&ParamFile::new(&param_data(name)).unwrap());
+}
+
+#[test]
+fn typed_rows_match_row_data() {
+    with_param("\\ActionButtonParam.param", |param| {
+        let rows = param.typed_rows::<ACTIONBUTTON_PARAM_ST>().unwrap();
+        assert_eq!(rows.len(), param.rows.len());
+
+        for ((id, typed), row) in rows.iter().zip(&param.rows) {
+            assert_eq!(*id, row.id);
+            assert_eq!(typed.as_bytes(), row.data);
+            assert_eq!(typed.regionType as u8, row.data[0]);
+            assert_eq!(
+                typed.regionType.to_string(),
+                format!("{:?}", typed.regionType)
+            );
+            assert_eq!(
+                typed.dummyPoly1,
+                i32::from_le_bytes(row.data[4..8].try_into().unwrap())
+            );
+            assert_eq!(
+                typed.overrideActionButtonIdForRide,
+                ActionButtonParamId(i32::from_le_bytes(row.data[64..68].try_into().unwrap()))
+            );
+
+            let cast: &ACTIONBUTTON_PARAM_ST = row.try_into().unwrap();
+            assert!(std::ptr::eq(cast, *typed));
+        }
+    });
+}
+
+#[test]
+fn size_mismatch_is_an_error() {
+    with_param("\\AtkParam_Pc.param", |param| {
+        assert!(param.typed_rows::<ACTIONBUTTON_PARAM_ST>().is_err());
+
+        let row: &Row = &param.rows[0];
+        assert!(<&ACTIONBUTTON_PARAM_ST>::try_from(row).is_err());
+        assert!(ACTIONBUTTON_PARAM_ST::from_bytes(&row.data[..4]).is_err());
+    });
+}
+
+#[test]
+fn reflection_get_set_by_name() {
+    with_param("\\ActionButtonParam.param", |param| {
+        let row = &param.rows[0];
+        let mut owned = OwnedRow {
+            id: row.id,
+            name: None,
+            data: row.data.to_vec(),
+        };
+        let typed = owned.get_mut::<ACTIONBUTTON_PARAM_ST>().unwrap();
+        let fields: &mut dyn ParamReflect = typed;
+
+        let info = fields.field("isInvalidForRide").unwrap();
+        assert_eq!((info.offset(), info.bit_width), (51, Some(1)));
+        assert_eq!(fields.get("regionType"), Some(FieldValue::U8(row.data[0])));
+        assert_eq!(fields.get("doesNotExist"), None);
+
+        fields.set("dummyPoly1", FieldValue::I32(-5)).unwrap();
+        fields.set("isInvalidForRide", FieldValue::U8(1)).unwrap();
+        assert_eq!(fields.get("isInvalidForRide"), Some(FieldValue::U8(1)));
+        assert!(fields.set("isInvalidForRide", FieldValue::U8(2)).is_err());
+        assert!(fields.set("dummyPoly1", FieldValue::F32(1.0)).is_err());
+        assert!(fields.set("doesNotExist", FieldValue::U8(0)).is_err());
+
+        let typed = owned.get::<ACTIONBUTTON_PARAM_ST>().unwrap();
+        assert_eq!(typed.dummyPoly1, -5);
+        assert!(typed.isInvalidForRide_get());
+    });
+}
+
+#[test]
+fn row_name_ids() {
+    assert_eq!(ActionButtonParam::ids::RIDE, 1100);
+    assert_eq!(ActionButtonParam::ids::TOUCH_BLOODSTAIN_1020, 1020);
+    assert_eq!(ActionButtonParam::NAMES.get(&1100), Some(&"Ride"));
+    assert_eq!(ActionButtonParam::IDS.get("Ride"), Some(&1100));
+    assert_eq!(ActionButtonParam::IDS.get("Touch bloodstain"), Some(&1020));
+}
+
+#[test]
+fn invalid_enum_value_is_an_error() {
+    with_param("\\ActionButtonParam.param", |param| {
+        let mut data = param.rows[0].data.to_vec();
+        data[0] = 0xFF;
+        assert!(ACTIONBUTTON_PARAM_ST::from_bytes(&data).is_err());
+
+        let row = Row {
+            id: 1234,
+            name: None,
+            data: &data,
+        };
+        let Err(err) = <&ACTIONBUTTON_PARAM_ST>::try_from(&row) else {
+            panic!("invalid row was accepted");
+        };
+        let err = err.to_string();
+        assert!(err.starts_with("Row 1234: Field regionType of "), "{}", err);
+        assert!(err.contains("--newtype-enums"), "{}", err);
+    });
+}
+
+#[test]
+fn missing_enums_are_reported() {
+    let bnd = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+    let db = ParamdexDB::load("paramdex").unwrap();
+    let diagnostics = RustCodegen::new(&bnd, &db, usize::MAX)
+        .unwrap()
+        .enum_diagnostics(&CodegenParams::default());
+
+    let of_field = |field: &str| {
+        diagnostics
+            .iter()
+            .find(|d| d.param == "ActionButtonParam" && d.field == field)
+    };
+    let diag = of_field("isInvalidForRide").unwrap();
+    assert_eq!(diag.enum_name, "BOOL_YESNO_TYPE");
+    assert_eq!(diag.kind, EnumDiagnosticKind::Missing);
+    assert!(of_field("regionType").is_none());
+}
+
+#[test]
+fn newtype_enums_accept_any_value() {
+    let config = CodegenParams {
+        newtype_enums: true,
+        ..Default::default()
+    };
+    let code = generate("paramdex", &["ActionButtonParam"], &config);
+    assert!(code.contains("pub struct ACTION_BUTTON_REGION_TYPE(pub u8);"));
+    assert!(code.contains("pub const Cylinder: Self = Self(0);"));
+    assert!(!code.contains("fn invalid_field"));
+}
+
+#[test]
+fn enum_try_from_integer() {
+    use action_button_param::ACTION_BUTTON_REGION_TYPE;
+    assert_eq!(
+        ACTION_BUTTON_REGION_TYPE::try_from(2),
+        Ok(ACTION_BUTTON_REGION_TYPE::Capsule)
+    );
+    assert_eq!(ACTION_BUTTON_REGION_TYPE::try_from(3), Err(3));
+}
+
+#[test]
+fn default_rows_use_paramdef_defaults() {
+    let row = ACTIONBUTTON_PARAM_ST::default();
+    assert_eq!(row.dummyPoly1, -1);
+    assert_eq!(row.angle, 180);
+    assert_eq!(row.overrideActionButtonIdForRide, ActionButtonParamId(-1));
+    assert_eq!(row.radius, 0.0);
+
+    let mut owned = OwnedParamFile::new(&param_data("\\ActionButtonParam.param")).unwrap();
+    let id = owned.rows.last().unwrap().id + 1;
+    let new_row = owned
+        .insert_default_row::<ACTIONBUTTON_PARAM_ST>(id, Some("New".to_owned()))
+        .unwrap();
+    new_row.angle = 90;
+    assert!(owned
+        .insert_default_row::<ACTIONBUTTON_PARAM_ST>(id, None)
+        .is_err());
+
+    let bytes = owned.to_bytes().unwrap();
+    let written = ParamFile::new(&bytes).unwrap();
+    let (_, row) = *written
+        .typed_rows::<ACTIONBUTTON_PARAM_ST>()
+        .unwrap()
+        .last()
+        .unwrap();
+    assert_eq!((row.angle, row.dummyPoly1), (90, -1));
+}
+
+#[test]
+fn invalid_enum_defaults_are_reported() {
+    let bnd = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+    let db = ParamdexDB::load("paramdex").unwrap();
+    let cg = RustCodegen::new(&bnd, &db, usize::MAX).unwrap();
+    let of_field = |config: &CodegenParams| {
+        cg.enum_diagnostics(config)
+            .into_iter()
+            .find(|d| d.param == "BulletParam" && d.field == "atkAttribute")
+    };
+
+    let diag = of_field(&CodegenParams::default()).unwrap();
+    assert_eq!(diag.enum_name, "ATKPARAM_ATKATTR_TYPE");
+    assert_eq!(
+        diag.kind,
+        EnumDiagnosticKind::InvalidDefault { default: 254 }
+    );
+    let code = generate("paramdex", &["BulletParam"], &CodegenParams::default());
+    assert!(code.contains("pub atkAttribute: u8,"));
+    assert!(code.contains("atkAttribute: 254,"));
+
+    // Newtype enums can hold the default
+    let newtype = CodegenParams {
+        newtype_enums: true,
+        ..Default::default()
+    };
+    assert!(of_field(&newtype).is_none());
+    let code = generate("paramdex", &["BulletParam"], &newtype);
+    assert!(code.contains("atkAttribute: ATKPARAM_ATKATTR_TYPE(254),"));
+}
+
+#[test]
+fn bitfield_accessors_only_touch_their_bits() {
+    let mut row = ACTIONBUTTON_PARAM_ST::default();
+    row.isGrayoutForRide_set(true);
+    row.padding5_set(1).unwrap();
+    assert!(row.isGrayoutForRide_get());
+    assert!(!row.isInvalidForRide_get());
+    assert_eq!(row.as_bytes()[51], 0b101);
+
+    assert!(row.padding5_set(2).is_err());
+    assert_eq!(row.padding5_get(), 1);
+    row.isGrayoutForRide_update(|v| !v);
+    assert_eq!(row.as_bytes()[51], 0b001);
+}
+
+#[test]
+fn big_endian_rows() {
+    use action_button_param_be::ACTIONBUTTON_PARAM_ST as BeRow;
+
+    let mut row = BeRow::default();
+    assert_eq!(row.angle, 180);
+    assert_eq!(row.overrideActionButtonIdForRide.0, -1);
+    let offset = row.field("angle").unwrap().offset();
+    assert_eq!(row.as_bytes()[offset..offset + 4], [0, 0, 0, 180]);
+
+    row.set("angle", FieldValue::I32(90)).unwrap();
+    assert_eq!(row.angle.get(), 90);
+    assert_eq!(row.get("angle"), Some(FieldValue::I32(90)));
+
+    let mut owned = OwnedParamFile::new(&param_data("\\ActionButtonParam.param")).unwrap();
+    assert!(owned.insert_default_row::<BeRow>(1, None).is_err());
+    owned.header.big_endian = true;
+    owned.rows.clear();
+    owned
+        .insert_default_row::<BeRow>(1, None)
+        .unwrap()
+        .angle
+        .set(45);
+    assert!(owned
+        .insert_default_row::<ACTIONBUTTON_PARAM_ST>(2, None)
+        .is_err());
+
+    let bytes = owned.to_bytes().unwrap();
+    let written = ParamFile::new(&bytes).unwrap();
+    assert!(written.typed_rows::<ACTIONBUTTON_PARAM_ST>().is_err());
+    let rows = written.typed_rows::<BeRow>().unwrap();
+    assert_eq!((rows[0].0, rows[0].1.angle.get()), (1, 45));
+}
+
+#[test]
+fn rows_round_trip_through_serde() {
+    let mut owned = OwnedParamFile::new(&param_data("\\ActionButtonParam.param")).unwrap();
+    for row in &mut owned.rows {
+        let original = row.data.clone();
+        let typed = row.get_mut::<ACTIONBUTTON_PARAM_ST>().unwrap();
+        let json = serde_json::to_string(typed).unwrap();
+
+        let read: ACTIONBUTTON_PARAM_ST = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&read).unwrap(), json);
+
+        typed.angle = 0;
+        typed.isInvalidForRide_set(false);
+        deserialize_into(typed, &mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!(row.data, original);
+    }
+
+    let value =
+        serde_json::to_value(owned.rows[0].get::<ACTIONBUTTON_PARAM_ST>().unwrap()).unwrap();
+    assert!(value["regionType"].is_string());
+    assert!(value["isInvalidForRide"].is_boolean());
+    assert!(value.get("padding1").is_none());
+}
+
+#[test]
+fn deserialized_rows_start_from_defaults() {
+    let row: ACTIONBUTTON_PARAM_ST =
+        serde_json::from_str(r#"{"regionType": 1, "angle": 90, "isGrayoutForRide": true}"#)
+            .unwrap();
+    assert_eq!(row.regionType as u8, 1);
+    assert_eq!((row.angle, row.dummyPoly1), (90, -1));
+    assert!(row.isGrayoutForRide_get());
+
+    let name = serde_json::to_value(row.regionType).unwrap();
+    let by_name: ACTIONBUTTON_PARAM_ST =
+        serde_json::from_value(serde_json::json!({ "regionType": name })).unwrap();
+    assert_eq!(by_name.regionType, row.regionType);
+
+    assert!(serde_json::from_str::<ACTIONBUTTON_PARAM_ST>(r#"{"regionType": 200}"#).is_err());
+    assert!(serde_json::from_str::<ACTIONBUTTON_PARAM_ST>(r#"{"padding1": [0, 0]}"#).is_err());
+
+    let be_row = action_button_param_be::ACTIONBUTTON_PARAM_ST::default();
+    assert_eq!(serde_json::to_value(be_row).unwrap()["angle"], 180);
+}
+
+#[test]
+fn rows_convert_between_versions() {
+    use sword_arts_param::{v0, SWORD_ARTS_PARAM_ST, SWORD_ARTS_SHIELD_ICON_TYPE};
+
+    with_param("\\SwordArtsParam.param", |param| {
+        for (_, row) in param.typed_rows::<SWORD_ARTS_PARAM_ST>().unwrap() {
+            let old = v0::SWORD_ARTS_PARAM_ST::try_from(row).unwrap();
+            assert_eq!(old.textId, row.textId);
+            assert_eq!(old.shieldIconType, SWORD_ARTS_SHIELD_ICON_TYPE::Type1);
+
+            let mut upgraded = SWORD_ARTS_PARAM_ST::try_from(&old).unwrap();
+            assert_eq!(upgraded.swordArtsTypeNew, 0);
+            upgraded.swordArtsTypeNew = row.swordArtsTypeNew;
+            assert_eq!(upgraded.as_bytes(), row.as_bytes());
+        }
+    });
+}
+
+/// Generates params the way the `codegen` subcommand does.
+fn generate(paramdex: &str, params: &[&str], config: &CodegenParams) -> String {
+    let bnd = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+    let db = ParamdexDB::load(paramdex).unwrap();
+    let cg = RustCodegen::new(&bnd, &db, usize::MAX).unwrap();
+
+    let mut code = String::new();
+    cg.gen_params(params, config, &mut code).unwrap();
+    code
+}
+
+/// Lists the paths of the items and imports of generated code, relative to the code's root.
+fn item_paths(code: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut mods: Vec<(&str, usize)> = Vec::new();
+    let mut depth = 0;
+    for line in code.lines().filter(|l| !l.trim_start().starts_with("//")) {
+        let mod_depth = mods.last().map_or(0, |&(_, d)| d);
+        if depth == mod_depth {
+            let names: Vec<_> = if let Some(import) = line.strip_prefix("use ") {
+                match import.split_once("::{") {
+                    Some((_, group)) => group.trim_end_matches("};").split(", ").collect(),
+                    None => vec![import.trim_end_matches(';').rsplit("::").next().unwrap()],
+                }
+            } else {
+                ["pub enum ", "pub struct ", "pub mod ", "pub use "]
+                    .iter()
+                    .filter_map(|p| line.strip_prefix(p))
+                    .map(|l| l.trim_end_matches(';').rsplit("::").next().unwrap())
+                    .map(|l| {
+                        l.split(|c: char| !c.is_alphanumeric() && c != '_')
+                            .next()
+                            .unwrap()
+                    })
+                    .collect()
+            };
+            for name in names.into_iter().filter(|&n| n != "*") {
+                let path: Vec<_> = mods.iter().map(|&(m, _)| m).chain([name]).collect();
+                paths.push(path.join("::"));
+            }
+            if let Some(name) = line
+                .strip_prefix("pub mod ")
+                .and_then(|l| l.strip_suffix(" {"))
+            {
+                mods.push((name, depth + 1));
+            }
+        }
+
+        let (mut in_str, mut escaped) = (false, false);
+        for c in line.chars() {
+            match (in_str, c) {
+                (true, _) if escaped => escaped = false,
+                (true, '\\') => escaped = true,
+                (true, '"') | (false, '"') => in_str = !in_str,
+                (false, '{') => depth += 1,
+                (false, '}') => {
+                    depth -= 1;
+                    if mods.last().is_some_and(|&(_, d)| d > depth) {
+                        mods.pop();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    paths
+}
+
+#[test]
+fn samples_are_up_to_date() {
+    let base = || CodegenParams {
+        reflection: true,
+        serde: true,
+        ..Default::default()
+    };
+    let samples = [
+        (
+            "tests/fixtures/test_param.rs",
+            "paramdex",
+            "ActionButtonParam",
+            base(),
+        ),
+        (
+            "tests/fixtures/test_param_be.rs",
+            "paramdex",
+            "ActionButtonParam",
+            CodegenParams {
+                big_endian: true,
+                ..base()
+            },
+        ),
+        (
+            "tests/fixtures/test_param_versions.rs",
+            "paramdex",
+            "SwordArtsParam",
+            CodegenParams {
+                versions: true,
+                newtype_enums: true,
+                ..Default::default()
+            },
+        ),
+        (
+            "tests/fixtures/fixture_param.rs",
+            FIXTURE_PARAMDEX,
+            "FixtureParam",
+            CodegenParams::default(),
+        ),
+    ];
+    for (path, paramdex, param, config) in samples {
+        let expected = std::fs::read_to_string(path).unwrap();
+        assert!(
+            generate(paramdex, &[param], &config) == expected,
+            "{} is out of date, regenerate it with the codegen subcommand",
+            path
+        );
+    }
+}
+
+#[test]
+fn tdf_enum_fields() {
+    use fixture_param::{ItemType, LotItemCategory, FIXTURE_PARAM_ST};
+
+    let bnd = ER::decrypt_regulation(&std::fs::read("regulations/er").unwrap()).unwrap();
+    let db = ParamdexDB::load(FIXTURE_PARAMDEX).unwrap();
+    let cg = RustCodegen::new(&bnd, &db, usize::MAX).unwrap();
+    assert_eq!(cg.enum_diagnostics(&CodegenParams::default()), []);
+
+    let mut row = FIXTURE_PARAM_ST::default();
+    assert_eq!(row.itemType, ItemType::Weapon);
+    assert_eq!(row.lotItemCategory, LotItemCategory::None);
+    row.lotItemCategory = LotItemCategory::Gem;
+    assert_eq!(row.as_bytes()[4..8], 5i32.to_ne_bytes());
+
+    // Generating every param moves the TDF enums to the shared module
+    let out_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("tdf_enum_fields");
+    cg.gen_all(&CodegenParams::default(), &out_dir, "mod.rs")
+        .unwrap();
+    let enums = std::fs::read_to_string(out_dir.join("enums.rs")).unwrap();
+    assert!(enums.contains("pub enum ItemType {"));
+    assert!(enums.contains("pub enum LotItemCategory {"));
+    let module = std::fs::read_to_string(out_dir.join("fixture_param.rs")).unwrap();
+    assert!(module.contains("use super::enums::{ItemType, LotItemCategory};"));
+    assert!(!module.contains("pub enum"));
+}
+
+#[test]
+fn enum_bitfields_are_checked_like_they_are_read() {
+    use fixture_param::{LotItemCategory, FIXTURE_PARAM_ST};
+
+    let mut data = FIXTURE_PARAM_ST::default().as_bytes().to_vec();
+    data[44..48].copy_from_slice(&(0xABCD00u32 | 5).to_le_bytes());
+    let row = FIXTURE_PARAM_ST::from_bytes(&data).unwrap();
+    assert_eq!(row.lotCategoryBits_get(), LotItemCategory::Gem);
+    assert_eq!(row.reserved_get(), 0xABCD);
+
+    data[44..48].copy_from_slice(&9u32.to_le_bytes());
+    let Err(err) = FIXTURE_PARAM_ST::from_bytes(&data) else {
+        panic!("invalid row was accepted");
+    };
+    assert!(err.to_string().contains("lotCategoryBits"), "{}", err);
+}
+
+#[test]
+fn fixed_string_fields() {
+    use fixture_param::FIXTURE_PARAM_ST;
+
+    let mut row = FIXTURE_PARAM_ST::default();
+    row.name.0[..6].copy_from_slice(&[0x83, 0x7B, 0x81, 0x5B, 0x83, 0x93]);
+    row.wideName.0[..3].copy_from_slice(&[0xC9, b't' as u16, 0xE9]);
+    assert_eq!(row.name.get(), "ボーン");
+    assert_eq!(row.wideName.get(), "Été");
+
+    let assert_reencoded = |row: &mut FIXTURE_PARAM_ST| {
+        let bytes = row.as_bytes().to_vec();
+        let (name, wide_name) = (row.name.get().into_owned(), row.wideName.get().into_owned());
+        row.name.set(&name).unwrap();
+        row.wideName.set(&wide_name).unwrap();
+        assert_eq!(row.as_bytes(), bytes);
+    };
+    assert_reencoded(&mut row);
+
+    // Strings filling the whole field have no null terminator
+    row.name.0 = *b"Bone_0123456789A";
+    row.wideName.0 = "abcdefgh"
+        .encode_utf16()
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    assert_eq!(row.name.get(), "Bone_0123456789A");
+    assert_eq!(row.wideName.get(), "abcdefgh");
+    assert_reencoded(&mut row);
+}
+
+#[test]
+fn is_bool_u8_field() {
+    let mut row = fixture_param::FIXTURE_PARAM_ST::default();
+    for (raw, value) in [(0, false), (1, true), (2, true)] {
+        row.isEnableParry = raw;
+        assert_eq!(row.isEnableParry_get(), value);
+        assert_eq!(row.isEnableParry, raw);
+    }
+
+    row.isEnableParry_set(false);
+    assert_eq!(row.isEnableParry, 0);
+    row.isEnableParry_set(true);
+    assert_eq!(row.isEnableParry, 1);
+}
+
+#[test]
+fn multi_param_items_are_unique() {
+    let config = CodegenParams {
+        reflection: true,
+        serde: true,
+        ..Default::default()
+    };
+    let code = generate("paramdex", &["BulletParam", "AtkParam"], &config);
+    let paths = item_paths(&code);
+    for path in [
+        "ATKPARAM_ATKATTR_TYPE",
+        "SpEffectParamId",
+        "atk_param::ATK_PARAM_ST",
+        "ATK_PARAM_ST",
+        "bullet_param::BULLET_PARAM_ST",
+    ] {
+        assert!(paths.iter().any(|p| p == path), "{} is not generated", path);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let duplicates: Vec<_> = paths.iter().filter(|p| !seen.insert(*p)).collect();
+    assert!(
+        duplicates.is_empty(),
+        "defined more than once: {:?}",
+        duplicates
+    );
+}
+
+#[test]
+fn docs_can_be_disabled() {
+    let config = CodegenParams {
+        docs: false,
+        reflection: true,
+        versions: true,
+        ..Default::default()
+    };
+    let code = generate("paramdex", &["ActionButtonParam", "BulletParam"], &config);
+    assert!(!code.contains("///"));
+}